@@ -10,7 +10,9 @@ pub fn VisualizarEvolucao() -> impl IntoView {
                 let validate = SignalResult::from_result(form_data_id.get())
                     .combine(SignalResult::from_option_result(form_data.get()));
                 match validate {
-                    SignalResult::Ok(hlist_pat!(form_data_id, form_data)) => {
+                    SignalResult::Ok(hlist_pat!(form_data_id, form_data))
+                    | SignalResult::Reloading(hlist_pat!(form_data_id, form_data))
+                    | SignalResult::Empty(hlist_pat!(form_data_id, form_data)) => {
                         EitherOf3::A(
                             view! {
                                 <h1 class="text-2xl font-bold">Formulário</h1>
@@ -0,0 +1,229 @@
+//! The `#[signal_result]` attribute, re-exported by the `signal_result` crate, and a helper
+//! of its view macros.
+
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote, parse_quote_spanned,
+    spanned::Spanned,
+    FnArg, Ident, ItemFn, Pat, Token, Type,
+};
+
+/// Turns a component taking resolved values into one taking their reactive sources.
+///
+/// Parameters marked `#[source]` are declared with the value they resolve to. The generated
+/// component takes any `Copy` source converting into a `SignalResult` of that value and the
+/// `error` type given to the attribute instead, and renders the body through
+/// `signal_result_view!` with the wrapper, loading and error views of the `SignalResultTheme` in
+/// context. Other parameters are regular props, cloned on every render. The function is turned
+/// into a `#[component]`, which must not be added separately.
+///
+/// ```rust,ignore
+/// #[signal_result(error = AppError)]
+/// pub fn FormView(#[source] form_data: FormData, #[prop(optional)] title: String) -> impl IntoView {
+///     view! {
+///         <h1 class="text-2xl font-bold">{title}</h1>
+///         <FormEvolucao form_data />
+///     }
+/// }
+///
+/// view! { <FormView form_data=queries::form_data::get_form_data(id) /> }
+/// ```
+#[proc_macro_attribute]
+pub fn signal_result(
+    attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    syn::parse(attr)
+        .and_then(|args| syn::parse(input).and_then(|item_fn| expand(args, item_fn)))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Expands to the tokens following the bindings of `signal_result_view!`, or to an error if
+/// two of them share a name.
+///
+/// The bindings are given as `[(name (source))*]`, as collected by the macro. `_` may repeat.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __unique_bindings(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    unique_bindings(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn unique_bindings(input: TokenStream) -> syn::Result<TokenStream> {
+    let mut tokens = input.into_iter();
+    let bindings = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => group,
+        _ => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "expected `[(name (source))*]`",
+            ))
+        }
+    };
+
+    let mut names: Vec<Ident> = Vec::new();
+    for binding in bindings.stream() {
+        let TokenTree::Group(binding) = binding else {
+            continue;
+        };
+        let Some(TokenTree::Ident(name)) = binding.stream().into_iter().next() else {
+            continue;
+        };
+        if name == "_" {
+            continue;
+        }
+        if names.contains(&name) {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("`{name}` is bound more than once in `signal_result_view!`"),
+            ));
+        }
+        names.push(name);
+    }
+
+    Ok(tokens.collect())
+}
+
+mod kw {
+    syn::custom_keyword!(error);
+}
+
+/// The arguments of `#[signal_result(error = AppError)]`.
+struct Args {
+    /// The error type of every source.
+    error: Type,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "`#[signal_result]` needs the error type of its sources: \
+                 `#[signal_result(error = AppError)]`",
+            ));
+        }
+
+        input.parse::<kw::error>()?;
+        input.parse::<Token![=]>()?;
+        let error = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        if !input.is_empty() {
+            return Err(input.error("`#[signal_result]` only takes `error = Type`"));
+        }
+
+        Ok(Self { error })
+    }
+}
+
+fn expand(args: Args, mut item_fn: ItemFn) -> syn::Result<TokenStream> {
+    let error = &args.error;
+    let component = item_fn.attrs.iter().find(|attr| {
+        attr.path()
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "component")
+    });
+    if let Some(component) = component {
+        return Err(syn::Error::new_spanned(
+            component,
+            "`#[signal_result]` already turns the function into a `#[component]`, remove this \
+             attribute",
+        ));
+    }
+
+    let mut sources = Vec::new();
+    let mut props = Vec::new();
+
+    for input in &mut item_fn.sig.inputs {
+        let arg = match input {
+            FnArg::Typed(arg) => arg,
+            FnArg::Receiver(receiver) => {
+                return Err(syn::Error::new_spanned(
+                    receiver,
+                    "`#[signal_result]` components cannot take `self`",
+                ))
+            }
+        };
+
+        let before = arg.attrs.len();
+        arg.attrs.retain(|attr| !attr.path().is_ident("source"));
+        let is_source = arg.attrs.len() < before;
+
+        let ident = match &*arg.pat {
+            Pat::Ident(pat) if !is_source || (pat.by_ref.is_none() && pat.mutability.is_none()) => {
+                pat.ident.clone()
+            }
+            pat if is_source => {
+                return Err(syn::Error::new_spanned(
+                    pat,
+                    "`#[source]` parameters must be plain identifiers",
+                ))
+            }
+            pat => {
+                return Err(syn::Error::new_spanned(
+                    pat,
+                    "component parameters must be identifiers",
+                ))
+            }
+        };
+
+        if is_source {
+            let ty = &arg.ty;
+            let result: Type = parse_quote_spanned! {ty.span()=>
+                ::signal_result::SignalResult<
+                    ::signal_result::__private::frunk::HCons<#ty, ::signal_result::__private::frunk::HNil>,
+                    #error,
+                >
+            };
+            arg.ty = parse_quote_spanned! {ty.span()=>
+                impl ::core::convert::Into<#result>
+                    + ::core::marker::Copy + ::core::marker::Send + ::core::marker::Sync + 'static
+            };
+            sources.push((ident, result));
+        } else {
+            props.push(ident);
+        }
+    }
+
+    if sources.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item_fn.sig.ident,
+            "`#[signal_result]` components need at least one `#[source]` parameter",
+        ));
+    }
+
+    let theme = Ident::new("theme", Span::mixed_site());
+    let views = Ident::new("views", Span::mixed_site());
+    let errors = Ident::new("errors", Span::mixed_site());
+    let body = &item_fn.block;
+    let (sources, results): (Vec<_>, Vec<_>) = sources.into_iter().unzip();
+
+    item_fn.block = parse_quote! {{
+        let #theme = ::signal_result::use_signal_result_theme();
+        let #views = ::core::clone::Clone::clone(&#theme);
+        #theme.wrap(move || {
+            #(
+                #[allow(clippy::clone_on_copy, clippy::redundant_clone)]
+                let #props = ::core::clone::Clone::clone(&#props);
+            )*
+            #(
+                let #sources = ::core::convert::Into::<#results>::into(#sources);
+            )*
+            ::signal_result::signal_result_view!(
+                |#(#sources),*| #body,
+                |#errors| #views.error_view(#errors),
+                #views.loading_view()
+            )
+        })
+    }};
+
+    Ok(quote! {
+        #[::leptos::component]
+        #item_fn
+    })
+}
@@ -0,0 +1,123 @@
+//! Bridges `SignalResult` sources into `async` code.
+//!
+//! Event handlers and effects sometimes need to wait until the combined page data is
+//! available before acting (focusing a field, starting an auto-submit, ...). [`when_ready`]
+//! turns a closure returning a `SignalResult` into a future that resolves as soon as the
+//! closure leaves the `Loading` state.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use futures::channel::oneshot;
+
+use super::*;
+
+/// Waits until `source` produces a value other than `SignalResult::Loading`.
+///
+/// `source` is run inside an isomorphic effect, so every signal, resource or memo it reads
+/// is tracked and the closure is re-run whenever one of them changes. The first `Ok` or
+/// `Err` it yields resolves the returned future; later changes are ignored.
+///
+/// The effect belongs to the current reactive owner. If that owner is disposed before the
+/// source is ready (for example, the component was unmounted), the future resolves to `None`
+/// instead of hanging forever.
+///
+/// # Examples
+///
+/// ```rust
+/// use signal_result::{when_ready, SignalResult};
+/// # #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// # enum AppError { PageNotFound }
+/// use frunk::hlist_pat;
+/// use leptos::prelude::*;
+/// # tokio_test::block_on(async move {
+/// # tokio::task::LocalSet::new().run_until(async move {
+/// # let _ = leptos::task::Executor::init_tokio();
+///
+/// let resource = Resource::new(|| (), |_| async { Ok::<_, AppError>(42) });
+/// let ready = when_ready(move || SignalResult::from(resource));
+///
+/// assert!(matches!(ready.await, Some(Ok(hlist_pat!(42)))));
+/// # });
+/// # });
+/// ```
+pub fn when_ready<T, E, F>(source: F) -> impl Future<Output = Option<Result<T, Vec<E>>>>
+where
+    T: HList + Send + 'static,
+    E: Send + 'static,
+    F: Fn() -> SignalResult<T, E> + Send + Sync + 'static,
+{
+    let (tx, rx) = oneshot::channel();
+    // The effect may run again after resolving, so the sender is taken on first use.
+    let tx = Arc::new(Mutex::new(Some(tx)));
+
+    let effect = Effect::new_isomorphic(move |_| {
+        if let Some(result) = source().into_ready() {
+            if let Some(tx) = tx.lock().expect("poisoned `when_ready` sender").take() {
+                let _ = tx.send(result);
+            }
+        }
+    });
+
+    async move {
+        let result = rx.await.ok();
+        // Nothing left to wait for, stop tracking the source.
+        effect.stop();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use frunk::hlist_pat;
+
+    use super::*;
+    use crate::test_util::{settle, with_owner};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum AppError {
+        PageNotFound,
+    }
+
+    #[tokio::test]
+    async fn test_when_ready_waits_for_the_source() {
+        with_owner(|_| async {
+            let value = RwSignal::new(None::<Result<i32, AppError>>);
+            let ready = when_ready(move || SignalResult::from_option_result(value.get()));
+            let mut ready = Box::pin(ready);
+            settle().await;
+            assert!(futures::poll!(&mut ready).is_pending());
+
+            value.set(Some(Ok(42)));
+            assert!(matches!(ready.await, Some(Ok(hlist_pat!(42)))));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_when_ready_resolves_errors() {
+        with_owner(|_| async {
+            let value = RwSignal::new(None::<Result<i32, AppError>>);
+            let ready = when_ready(move || SignalResult::from_option_result(value.get()));
+
+            value.set(Some(Err(AppError::PageNotFound)));
+            assert!(matches!(ready.await, Some(Err(errors)) if errors == [AppError::PageNotFound]));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_when_ready_after_disposal() {
+        with_owner(|owner| async move {
+            let child = owner.child();
+            let ready = child.with(|| {
+                let value = RwSignal::new(None::<Result<i32, AppError>>);
+                when_ready(move || SignalResult::from_option_result(value.get()))
+            });
+
+            child.cleanup();
+            assert!(ready.await.is_none());
+        })
+        .await;
+    }
+}
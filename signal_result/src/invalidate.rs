@@ -0,0 +1,336 @@
+//! Mutation-driven invalidation of `SignalResult` sources.
+//!
+//! After a `ServerAction` saves something, the sources showing that data are out of date.
+//! [`invalidate_on_ok`] declares which sources an action invalidates; whenever the action
+//! completes with `Ok`, every target is refetched. Targets that keep their previous value
+//! while refetching, such as [`ReloadableResource`] and [`Query`], report
+//! `SignalResult::Reloading` in the meantime.
+
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::*;
+
+/// Something that can be marked out of date and refetched.
+///
+/// Implemented for resources and [`ReloadableResource`] (with the default `serde` feature),
+/// `(QueryCache, QueryKey)` pairs and tuples of other targets.
+pub trait Invalidate: 'static {
+    /// Refetches the data behind this target.
+    fn invalidate(&self);
+}
+
+/// Refetches the resource. It keeps showing its previous value as `Ok` meanwhile; use a
+/// [`ReloadableResource`] to report `Reloading` instead.
+#[cfg(feature = "serde")]
+impl<T> Invalidate for Resource<T>
+where
+    T: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn invalidate(&self) {
+        self.refetch();
+    }
+}
+
+impl Invalidate for (QueryCache, QueryKey) {
+    fn invalidate(&self) {
+        self.0.invalidate(&self.1);
+    }
+}
+
+macro_rules! impl_invalidate_for_tuple {
+    ($($target:ident),+) => {
+        impl<$($target),+> Invalidate for ($($target,)+)
+        where
+            $($target: Invalidate),+
+        {
+            #[allow(non_snake_case)]
+            fn invalidate(&self) {
+                let ($($target,)+) = self;
+                $($target.invalidate();)+
+            }
+        }
+    };
+}
+
+impl_invalidate_for_tuple!(A);
+impl_invalidate_for_tuple!(A, B);
+impl_invalidate_for_tuple!(A, B, C);
+impl_invalidate_for_tuple!(A, B, C, D);
+impl_invalidate_for_tuple!(A, B, C, D, E);
+impl_invalidate_for_tuple!(A, B, C, D, E, F);
+
+/// Invalidates `targets` every time `action` completes with `Ok`.
+///
+/// Errors leave the targets untouched, so a failed save keeps showing the data as it was.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let form_data = ReloadableResource::new(queries::form_data::get_form_data(id));
+/// let save_form = ServerAction::<UpsertFormDataSrv>::new();
+///
+/// invalidate_on_ok(save_form, (form_data, (use_query_cache(), QueryKey::from_path(&path))));
+/// ```
+pub fn invalidate_on_ok<I, O, E>(
+    action: impl Into<Action<I, Result<O, E>>>,
+    targets: impl Invalidate,
+) where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    let action = action.into();
+    Effect::watch(
+        move || action.version().get(),
+        move |_, _, _| {
+            if action
+                .value()
+                .with_untracked(|value| matches!(value, Some(Ok(_))))
+            {
+                targets.invalidate();
+            }
+        },
+        false,
+    );
+}
+
+/// A `Resource` that reports `SignalResult::Reloading` while it is being refetched.
+///
+/// A plain `Resource` keeps its previous value during a refetch, so it cannot be told apart
+/// from a settled one. Refetching through [`ReloadableResource::reload`] (or invalidating
+/// it) tracks the refresh until the new value arrives.
+pub struct ReloadableResource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    resource: Resource<Result<T, E>>,
+    /// The number of reloads still waiting for their value.
+    reloading: RwSignal<usize>,
+}
+
+impl<T, E> Clone for ReloadableResource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E> Copy for ReloadableResource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+}
+
+impl<T, E> ReloadableResource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    /// Wraps `resource`.
+    pub fn new(resource: Resource<Result<T, E>>) -> Self {
+        Self {
+            resource,
+            reloading: RwSignal::new(0),
+        }
+    }
+
+    /// Refetches the resource, reporting `Reloading` until the new value arrives.
+    #[cfg(feature = "serde")]
+    pub fn reload(&self)
+    where
+        T: Clone + Serialize + DeserializeOwned,
+        E: Clone + Serialize + DeserializeOwned,
+    {
+        let this = *self;
+        if this
+            .reloading
+            .try_update(|reloading| *reloading += 1)
+            .is_none()
+        {
+            // The owner of the resource has been disposed.
+            return;
+        }
+        this.resource.refetch();
+        leptos::task::spawn_local(async move {
+            // The resource only starts loading once the refetch has been scheduled.
+            leptos::task::Executor::tick().await;
+            // The resource can't be awaited once disposed, along with `reloading`.
+            if this.reloading.is_disposed() {
+                return;
+            }
+            let _ = this.resource.await;
+            // The owner may have been disposed while the value was loading.
+            this.reloading.try_update(|reloading| *reloading -= 1);
+        });
+    }
+
+    /// Whether a refetch started by [`ReloadableResource::reload`] is still in flight.
+    pub fn is_reloading(&self) -> bool {
+        self.reloading.get() > 0
+    }
+
+    /// Returns the resource's value as a `SignalResult`, tracking it reactively.
+    pub fn get(&self) -> SignalResult<HCons<T, HNil>, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        match self.resource.get() {
+            Some(Ok(t)) if self.is_reloading() => SignalResult::Reloading(hlist![t]),
+            value => SignalResult::from_option_result(value),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, E> Invalidate for ReloadableResource<T, E>
+where
+    T: Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+    E: Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn invalidate(&self) {
+        self.reload();
+    }
+}
+
+impl<H, E> From<ReloadableResource<H, E>> for SignalResult<HCons<H, HNil>, E>
+where
+    H: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + 'static,
+{
+    fn from(value: ReloadableResource<H, E>) -> Self {
+        value.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[cfg(feature = "serde")]
+    use frunk::hlist_pat;
+
+    use super::*;
+    #[cfg(feature = "serde")]
+    use crate::test_util::counting_fetcher;
+    use crate::test_util::{settle, with_owner};
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    enum AppError {
+        PageNotFound,
+    }
+
+    #[derive(Clone, Default)]
+    struct Counter(Arc<AtomicUsize>);
+
+    impl Counter {
+        fn count(&self) -> usize {
+            self.0.load(Ordering::SeqCst)
+        }
+    }
+
+    impl Invalidate for Counter {
+        fn invalidate(&self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_on_ok() {
+        with_owner(|_| async {
+            let save = Action::new(|ok: &bool| {
+                let ok = *ok;
+                async move {
+                    if ok {
+                        Ok(())
+                    } else {
+                        Err(AppError::PageNotFound)
+                    }
+                }
+            });
+            let (first, second) = (Counter::default(), Counter::default());
+            invalidate_on_ok(save, (first.clone(), second.clone()));
+
+            save.dispatch(false);
+            settle().await;
+            assert_eq!((first.count(), second.count()), (0, 0));
+
+            save.dispatch(true);
+            settle().await;
+            assert_eq!((first.count(), second.count()), (1, 1));
+        })
+        .await;
+    }
+
+    #[cfg(feature = "serde")]
+    fn counting_resource() -> (ReloadableResource<usize, AppError>, Arc<AtomicUsize>) {
+        let (fetches, fetcher) = counting_fetcher(None);
+        let resource = ReloadableResource::new(Resource::new(|| (), move |_| fetcher()));
+        (resource, fetches)
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_reload_keeps_the_previous_value() {
+        with_owner(|_| async {
+            let (resource, _) = counting_resource();
+            settle().await;
+            assert!(matches!(resource.get(), SignalResult::Ok(hlist_pat!(0))));
+
+            resource.reload();
+            assert!(matches!(
+                resource.get(),
+                SignalResult::Reloading(hlist_pat!(0))
+            ));
+            settle().await;
+            assert!(matches!(resource.get(), SignalResult::Ok(hlist_pat!(1))));
+        })
+        .await;
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_overlapping_reloads() {
+        with_owner(|_| async {
+            let (resource, _) = counting_resource();
+            settle().await;
+
+            resource.reload();
+            leptos::task::Executor::tick().await;
+            resource.invalidate();
+            assert!(resource.is_reloading());
+            settle().await;
+            assert!(!resource.is_reloading());
+            assert!(matches!(resource.get(), SignalResult::Ok(hlist_pat!(2))));
+        })
+        .await;
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_reload_after_disposal() {
+        with_owner(|owner| async move {
+            let child = owner.child();
+            let (resource, fetches) = child.with(counting_resource);
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+            child.with(|| resource.reload());
+            child.cleanup();
+            settle().await;
+            resource.reload();
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+            assert_eq!(resource.reloading.try_get_untracked(), None);
+        })
+        .await;
+    }
+}
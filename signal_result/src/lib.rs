@@ -0,0 +1,809 @@
+//! This crate provides the `SignalResult` type, which is designed to handle asynchronous
+//! operations in Leptos applications, particularly when working with Signals, Resources, and Memos.
+//!
+//! `SignalResult` is generic over the error type `E`; applications usually pin it with an alias:
+//!
+//! ```rust
+//! # #[derive(Debug, Clone)]
+//! # enum AppError { PageNotFound }
+//! pub type SignalResult<T> = signal_result::SignalResult<T, AppError>;
+//! ```
+//!
+//! # Features
+//!
+//! - `leptos` (default): sources built on Leptos primitives, the view macros,
+//!   [`#[signal_result]`](signal_result) and [`SignalResultTheme`].
+//! - `components` (default): the default views of [`SignalResultTheme`] and
+//!   [`SignalResultBoundary`].
+//! - `serde` (default): `Serialize`/`Deserialize` for `SignalResult`, and the sources that
+//!   refetch a `Resource` ([`ReloadableResource::reload`], [`PollingSource`]), as Leptos only
+//!   refetches resources whose value it can serialize.
+//! - `router`: `err(...) => redirect(...)` arms in the view macros.
+
+pub(crate) use frunk::hlist;
+use frunk::prelude::*;
+use frunk::{HCons, HNil};
+#[cfg(feature = "leptos")]
+use leptos::prelude::*;
+
+#[cfg(feature = "leptos")]
+pub mod future;
+#[cfg(feature = "leptos")]
+pub mod invalidate;
+#[cfg(feature = "leptos")]
+pub mod macros;
+#[cfg(feature = "leptos")]
+pub mod paged;
+#[cfg(all(feature = "leptos", feature = "serde"))]
+pub mod polling;
+#[cfg(feature = "leptos")]
+pub mod query;
+pub mod report;
+#[cfg(feature = "leptos")]
+pub mod stream;
+#[cfg(all(test, feature = "leptos"))]
+mod test_util;
+#[cfg(feature = "leptos")]
+pub mod theme;
+#[cfg(feature = "leptos")]
+pub use future::when_ready;
+#[cfg(feature = "leptos")]
+pub use invalidate::{invalidate_on_ok, Invalidate, ReloadableResource};
+#[cfg(feature = "leptos")]
+pub use paged::{LoadMoreState, PagedSource};
+#[cfg(all(feature = "leptos", feature = "serde"))]
+pub use polling::{
+    BrowserPollDriver, ManualPollDriver, PollDriver, PollEvent, PollOptions, PollingSource,
+};
+#[cfg(feature = "leptos")]
+pub use query::{
+    provide_query_cache, use_query_cache, Clock, ManualClock, Query, QueryCache, QueryKey,
+    QueryOptions, SystemClock,
+};
+pub use report::{debug_key, dedup_errors, dedup_errors_by, ErrorReport, ReportedError};
+#[cfg(feature = "leptos")]
+pub use signal_result_macros::signal_result;
+#[cfg(feature = "leptos")]
+pub use stream::StreamSource;
+#[cfg(feature = "components")]
+pub use theme::SignalResultBoundary;
+#[cfg(feature = "leptos")]
+pub use theme::{provide_signal_result_theme, use_signal_result_theme, SignalResultTheme};
+
+/// Dependencies used by the macros, so they expand in crates that do not depend on them.
+#[doc(hidden)]
+pub mod __private {
+    pub use frunk;
+    #[cfg(feature = "leptos")]
+    pub use leptos;
+
+    /// Converts a source of the view macros, so that invalid sources are reported on their own
+    /// span rather than on the whole macro call.
+    pub fn source<S, T, E>(source: S) -> crate::SignalResult<T, E>
+    where
+        S: Into<crate::SignalResult<T, E>>,
+        T: frunk::prelude::HList,
+    {
+        source.into()
+    }
+
+    /// Rejects bindings of the view macros that share a name, naming the binding.
+    #[cfg(feature = "leptos")]
+    pub use signal_result_macros::__unique_bindings as unique_bindings;
+
+    /// Checks that a view given to the view macros can be rendered.
+    #[cfg(feature = "leptos")]
+    pub fn view<V>(view: V) -> impl leptos::prelude::IntoView
+    where
+        V: View,
+    {
+        view
+    }
+
+    /// Renders the error view of the view macros, checking that it is a closure taking the
+    /// errors.
+    pub fn error_view<E, V>(errors: Vec<E>, error_view: impl FnOnce(Vec<E>) -> V) -> V {
+        error_view(errors)
+    }
+
+    #[cfg(feature = "leptos")]
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` cannot be rendered by `signal_result_view!`",
+        label = "expected a view",
+        note = "the views of `signal_result_view!` must implement `IntoView`"
+    )]
+    pub trait View: leptos::prelude::IntoView {}
+
+    #[cfg(feature = "leptos")]
+    impl<V> View for V where V: leptos::prelude::IntoView {}
+
+    /// Renders a `Redirect` to `path`, for `err(...) => redirect(path)` arms.
+    #[cfg(feature = "router")]
+    pub fn redirect(path: impl std::fmt::Display + 'static) -> impl leptos::prelude::IntoView {
+        leptos_router::components::Redirect(
+            leptos_router::components::RedirectProps::builder()
+                .path(path)
+                .build(),
+        )
+    }
+}
+
+/// `SignalResult` is a type that represents the state of asynchronous operations in Leptos.
+///
+/// It is particularly useful for handling the return values of `get()` methods on Leptos
+/// primitives such as Signals, Resources, and Memos.
+///
+/// # States
+///
+/// - `Loading`: The operation is still in progress.
+/// - `Reloading(T)`: A previous value of type `T` is available, but a refresh is in progress.
+/// - `Ok(T)`: The operation completed successfully with a value of type `T`.
+/// - `Empty(T)`: The operation completed successfully, but the value of type `T` has nothing
+///   to show. Sources only produce it when opted in with [`SignalResult::empty_when`].
+/// - `Err(Vec<E>)`: The operation failed with one or more errors.
+///
+/// # Type Parameters
+///
+/// - `T`: A heterogeneous list (`HList`) representing the successful result(s) of the operation(s).
+/// - `E`: The error type of the operations.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+#[cfg_attr(feature = "leptos", doc = "```rust")]
+#[cfg_attr(not(feature = "leptos"), doc = "```rust,ignore")]
+/// use signal_result::SignalResult;
+/// # #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// # enum AppError { PageNotFound }
+/// use frunk::{hlist, hlist_pat};
+/// use leptos::prelude::*;
+/// # tokio_test::block_on(async move {
+/// # tokio::task::LocalSet::new().run_until(async move {
+///
+/// let resource = Resource::new(|| (), |_| async { Ok::<_, AppError>(42) });
+/// let result = SignalResult::from_option_result(resource.get());
+///
+/// match result {
+///     SignalResult::Ok(hlist_pat!(value)) => println!("Value: {}", value),
+///     SignalResult::Reloading(hlist_pat!(value)) => println!("Stale value: {}", value),
+///     SignalResult::Empty(_) => println!("Nothing to show"),
+///     SignalResult::Err(ref errors) => println!("Errors: {:?}", errors),
+///     SignalResult::Loading => println!("Still loading..."),
+/// }
+///
+/// assert!(matches!(result, SignalResult::Ok(hlist![42])));
+/// # });
+/// # });
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignalResult<T, E>
+where
+    T: HList,
+{
+    Loading,
+    Reloading(T),
+    Ok(T),
+    Empty(T),
+    Err(Vec<E>),
+}
+
+impl<T, E> SignalResult<T, E>
+where
+    T: HList,
+{
+    /// Converts this `SignalResult` into a plain `Result`, or `None` while it is still loading.
+    ///
+    /// `Reloading` and `Empty` values are considered ready and are returned as `Ok`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signal_result::SignalResult;
+    /// use frunk::{hlist, HList};
+    ///
+    /// assert!(matches!(SignalResult::<_, ()>::Ok(hlist![42]).into_ready(), Some(Ok(_))));
+    /// assert!(matches!(SignalResult::<HList!(i32), ()>::Loading.into_ready(), None));
+    /// ```
+    pub fn into_ready(self) -> Option<Result<T, Vec<E>>> {
+        match self {
+            SignalResult::Loading => None,
+            SignalResult::Reloading(t) | SignalResult::Ok(t) | SignalResult::Empty(t) => {
+                Some(Ok(t))
+            }
+            SignalResult::Err(e) => Some(Err(e)),
+        }
+    }
+
+    /// Removes repeated errors, comparing them with [`debug_key`].
+    ///
+    /// See [`SignalResult::dedup_errors_by`].
+    pub fn dedup_errors(self) -> Self
+    where
+        E: std::fmt::Debug,
+    {
+        self.dedup_errors_by(debug_key)
+    }
+
+    /// Removes errors whose `key` was already seen, keeping the first of each.
+    ///
+    /// When several sources fail for the same reason, the combined `SignalResult` holds the
+    /// same error once per source. To also count the occurrences and keep track of the
+    /// failing sources, build an [`ErrorReport`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signal_result::SignalResult;
+    /// # #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    /// # enum AppError { PageNotFound }
+    /// use frunk::HList;
+    ///
+    /// let a = SignalResult::<HList!(i32), _>::Err(vec![AppError::PageNotFound]);
+    /// let b = SignalResult::<HList!(i32), _>::Err(vec![AppError::PageNotFound]);
+    ///
+    /// let combined = a.combine(b).dedup_errors();
+    /// assert!(matches!(combined, SignalResult::Err(errors) if errors.len() == 1));
+    /// ```
+    pub fn dedup_errors_by<K>(self, key: impl Fn(&E) -> K) -> Self
+    where
+        K: PartialEq,
+    {
+        match self {
+            SignalResult::Err(errors) => SignalResult::Err(dedup_errors_by(errors, key)),
+            result => result,
+        }
+    }
+}
+
+impl<H, T, E> SignalResult<HCons<H, T>, E>
+where
+    HCons<H, T>: HList,
+{
+    /// Combines this `SignalResult` with another, producing a new `SignalResult` that contains
+    /// the results of both if they are both `Ok`, or the appropriate error or loading state otherwise.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `H2`, `T2`: The type of the other `SignalResult` to combine with.
+    /// - `HResult`: The resulting type after combination.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "leptos", doc = "```rust")]
+    #[cfg_attr(not(feature = "leptos"), doc = "```rust,ignore")]
+    /// use signal_result::SignalResult;
+    /// # #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    /// # enum AppError { PageNotFound }
+    /// use frunk::{hlist, hlist_pat};
+    /// use leptos::prelude::*;
+    /// # tokio_test::block_on(async move {
+    /// # tokio::task::LocalSet::new().run_until(async move {
+    ///
+    /// let resource1 = Resource::new(|| (), |_| async { Ok::<_, AppError>(42) });
+    /// let resource2 = Resource::new(|| (), |_| async { Ok::<_, AppError>(true) });
+    ///
+    /// let result1 = SignalResult::from_option_result(resource1.get());
+    /// let result2 = SignalResult::from_option_result(resource2.get());
+    ///
+    /// let combined = result1.combine(result2);
+    ///
+    /// match combined {
+    ///     SignalResult::Ok(hlist_pat!(num, boolean)) => println!("Number: {}, Bool: {}", num, boolean),
+    ///     SignalResult::Reloading(_) => println!("Refreshing..."),
+    ///     SignalResult::Empty(_) => println!("Nothing to show"),
+    ///     SignalResult::Err(ref errors) => println!("Errors: {:?}", errors),
+    ///     SignalResult::Loading => println!("Still loading..."),
+    /// }
+    ///
+    /// assert!(matches!(combined, SignalResult::Ok(hlist![42, true])));
+    /// # });
+    /// # });
+    /// ```
+    pub fn combine<H2, T2, HResult>(
+        self,
+        other: SignalResult<HCons<H2, T2>, E>,
+    ) -> SignalResult<HResult, E>
+    where
+        HCons<H2, T2>: HList,
+        HResult: HList,
+        HCons<H, T>: std::ops::Add<HCons<H2, T2>, Output = HResult>,
+    {
+        combine(self, other)
+    }
+}
+
+impl<H, E> SignalResult<HCons<H, HNil>, E> {
+    /// Creates a `SignalResult` from an `Option<Result<H, E>>`.
+    ///
+    /// This method is particularly useful when working with Leptos Resources.
+    /// Specifically, it's designed to handle the return type of `Resource<Result<T, E>>::get()`,
+    /// which typically returns an `Option<Result<T, E>>`.
+    ///
+    /// Note: The `Option` in the return type of `Resource::get()` is used to handle the Loading state.
+    /// When the resource is still loading, `get()` returns `None`, which this method translates into
+    /// `SignalResult::Loading`.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "leptos", doc = "```rust")]
+    #[cfg_attr(not(feature = "leptos"), doc = "```rust,ignore")]
+    /// use signal_result::SignalResult;
+    /// # #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    /// # enum AppError { PageNotFound }
+    /// use frunk::hlist;
+    /// use leptos::prelude::*;
+    /// # tokio_test::block_on(async move {
+    /// # tokio::task::LocalSet::new().run_until(async move {
+    ///
+    /// let resource: Resource<Result<i32, AppError>> = Resource::new(|| (), |_| async { Ok(42) });
+    /// let result = SignalResult::from_option_result(resource.get());
+    ///
+    /// assert!(matches!(result, SignalResult::Ok(hlist![42])));
+    /// # });
+    /// # });
+    /// ```
+    pub fn from_option_result(value: Option<Result<H, E>>) -> Self {
+        match value {
+            Some(Ok(t)) => SignalResult::Ok(hlist![t]),
+            Some(Err(e)) => SignalResult::Err(vec![e]),
+            None => SignalResult::Loading,
+        }
+    }
+
+    /// Creates a `SignalResult` from a `Result<H, E>`.
+    ///
+    /// This method is particularly useful when working with `Memo<Result<T, E>>`,
+    /// as the `get()` method on such memos typically returns `Result<T, E>`.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "leptos", doc = "```rust")]
+    #[cfg_attr(not(feature = "leptos"), doc = "```rust,ignore")]
+    /// use signal_result::SignalResult;
+    /// # #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    /// # enum AppError { PageNotFound }
+    /// use frunk::hlist;
+    /// use leptos::prelude::*;
+    /// # tokio_test::block_on(async move {
+    /// # tokio::task::LocalSet::new().run_until(async move {
+    ///
+    /// let memo: Memo<Result<i32, AppError>> = Memo::new(move |_| Ok(42));
+    /// let signal_result = SignalResult::from_result(memo.get());
+    ///
+    /// assert!(matches!(signal_result, SignalResult::Ok(hlist![42])));
+    /// # });
+    /// # });
+    /// ```
+    pub fn from_result(value: Result<H, E>) -> Self {
+        match value {
+            Ok(t) => SignalResult::Ok(hlist![t]),
+            Err(e) => SignalResult::Err(vec![e]),
+        }
+    }
+
+    /// Creates a `SignalResult` from an `Option<H>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signal_result::SignalResult;
+    /// use frunk::hlist;
+    /// let option = Some(42);
+    /// let signal_result = SignalResult::<_, ()>::from_option(option);
+    ///
+    /// assert!(matches!(signal_result, SignalResult::Ok(hlist![42])));
+    /// ```
+    pub fn from_option(value: Option<H>) -> Self {
+        match value {
+            Some(t) => SignalResult::Ok(hlist![t]),
+            None => SignalResult::Loading,
+        }
+    }
+
+    /// Replaces errors with a value computed by `f`.
+    ///
+    /// `f` is called for each error and returns `Some(value)` for the errors it can recover
+    /// from. If every error is recovered, the result is `Ok` with the value recovered from the
+    /// first one; otherwise the errors `f` returned `None` for are kept and the rest dropped.
+    ///
+    /// This works on a single source, so it should be applied before `combine`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signal_result::SignalResult;
+    /// # #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    /// # enum AppError { PageNotFound }
+    /// use frunk::HList;
+    ///
+    /// let result = SignalResult::<HList!(Vec<i32>), _>::Err(vec![AppError::PageNotFound])
+    ///     .recover(|e| matches!(e, AppError::PageNotFound).then(Vec::new));
+    ///
+    /// assert!(matches!(result, SignalResult::Ok(_)));
+    /// ```
+    pub fn recover(self, f: impl Fn(&E) -> Option<H>) -> Self {
+        let SignalResult::Err(errors) = self else {
+            return self;
+        };
+
+        let mut recovered = None;
+        let mut unrecovered = Vec::new();
+        for error in errors {
+            match f(&error) {
+                Some(value) => {
+                    recovered.get_or_insert(value);
+                }
+                None => unrecovered.push(error),
+            }
+        }
+
+        match recovered {
+            Some(value) if unrecovered.is_empty() => SignalResult::Ok(hlist![value]),
+            _ => SignalResult::Err(unrecovered),
+        }
+    }
+
+    /// Replaces the errors matching `matches` with the value returned by `fallback`.
+    ///
+    /// Other errors are kept unchanged; see [`SignalResult::recover`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signal_result::SignalResult;
+    /// # #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    /// # enum AppError { PageNotFound }
+    /// use frunk::hlist;
+    ///
+    /// let result = SignalResult::from_result(Err::<i32, _>(AppError::PageNotFound))
+    ///     .or_else(|e| matches!(e, AppError::PageNotFound), || -1);
+    ///
+    /// assert!(matches!(result, SignalResult::Ok(hlist![-1])));
+    /// ```
+    pub fn or_else(self, matches: impl Fn(&E) -> bool, fallback: impl Fn() -> H) -> Self {
+        self.recover(|e| matches(e).then(&fallback))
+    }
+
+    /// Replaces the errors matching `matches` with `H::default()`.
+    ///
+    /// Useful to show a missing list as an empty one:
+    ///
+    /// ```rust
+    /// use signal_result::SignalResult;
+    /// # #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    /// # enum AppError { PageNotFound }
+    ///
+    /// let forms = SignalResult::from_result(Err::<Vec<String>, _>(AppError::PageNotFound))
+    ///     .or_default(|e| matches!(e, AppError::PageNotFound));
+    ///
+    /// assert!(matches!(forms, SignalResult::Ok(ref list) if list.head.is_empty()));
+    /// ```
+    pub fn or_default(self, matches: impl Fn(&E) -> bool) -> Self
+    where
+        H: Default,
+    {
+        self.or_else(matches, H::default)
+    }
+
+    /// Turns an `Ok` or `Reloading` value for which `is_empty` returns `true` into `Empty`.
+    ///
+    /// This is opt-in per source, so a successful-but-empty list can render an empty state
+    /// instead of the `Ok` view.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signal_result::SignalResult;
+    ///
+    /// let forms = SignalResult::<_, ()>::from_option(Some(Vec::<String>::new())).empty_when(Vec::is_empty);
+    ///
+    /// assert!(matches!(forms, SignalResult::Empty(_)));
+    /// ```
+    pub fn empty_when(self, is_empty: impl Fn(&H) -> bool) -> Self {
+        match self {
+            SignalResult::Ok(t) | SignalResult::Reloading(t) if is_empty(&t.head) => {
+                SignalResult::Empty(t)
+            }
+            other => other,
+        }
+    }
+}
+
+/// A refetching resource keeps its previous value, so it converts to `Ok`, never
+/// `Reloading`. Wrap it in a [`ReloadableResource`] to show refreshes.
+#[cfg(feature = "leptos")]
+impl<H, E> From<Resource<Result<H, E>>> for SignalResult<HCons<H, HNil>, E>
+where
+    H: Clone + Send + Sync,
+    E: Clone + Send + Sync,
+{
+    fn from(value: Resource<Result<H, E>>) -> Self {
+        SignalResult::from_option_result(value.get())
+    }
+}
+
+#[cfg(feature = "leptos")]
+impl<H, E> From<Memo<Result<H, E>>> for SignalResult<HCons<H, HNil>, E>
+where
+    H: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + 'static,
+{
+    fn from(value: Memo<Result<H, E>>) -> Self {
+        SignalResult::from_result(value.get())
+    }
+}
+
+/// Combines two `SignalResult`s into a single `SignalResult`.
+///
+/// This function is used internally by the `combine` method.
+fn combine<H0, T0, H1, T1, HResult, E>(
+    right: SignalResult<HCons<H0, T0>, E>,
+    left: SignalResult<HCons<H1, T1>, E>,
+) -> SignalResult<HResult, E>
+where
+    HCons<H0, T0>: HList,
+    HCons<H1, T1>: HList,
+    HResult: HList,
+    HCons<H0, T0>: std::ops::Add<HCons<H1, T1>, Output = HResult>,
+{
+    // Until all the signals are loaded, we return loading.
+    // If one of the signals returns an error, we return the error.
+    // If one of the signals is empty, we return the combined results as empty.
+    // If one of the signals is reloading, we return the combined results as reloading.
+    // If both signals return Ok, we return the result of combining the two results.
+    match (right, left) {
+        (SignalResult::Loading, _) => SignalResult::Loading,
+        (_, SignalResult::Loading) => SignalResult::Loading,
+        (SignalResult::Err(e), SignalResult::Err(e_other)) => {
+            SignalResult::Err(e.into_iter().chain(e_other).collect())
+        }
+        (SignalResult::Err(e), _) | (_, SignalResult::Err(e)) => SignalResult::Err(e),
+        (
+            SignalResult::Empty(t),
+            SignalResult::Ok(t_other)
+            | SignalResult::Reloading(t_other)
+            | SignalResult::Empty(t_other),
+        )
+        | (SignalResult::Ok(t) | SignalResult::Reloading(t), SignalResult::Empty(t_other)) => {
+            SignalResult::Empty(t.extend(t_other))
+        }
+        (
+            SignalResult::Reloading(t),
+            SignalResult::Ok(t_other) | SignalResult::Reloading(t_other),
+        )
+        | (SignalResult::Ok(t), SignalResult::Reloading(t_other)) => {
+            SignalResult::Reloading(t.extend(t_other))
+        }
+        (SignalResult::Ok(t), SignalResult::Ok(t_other)) => SignalResult::Ok(t.extend(t_other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frunk::{hlist, hlist_pat, HCons, HNil};
+
+    /// Stands in for `std::assert_matches`, which is unstable.
+    macro_rules! assert_matches {
+        ($value:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+            match $value {
+                $pattern $(if $guard)? => {}
+                ref value => panic!(
+                    "assertion failed: `{:?}` does not match `{}`",
+                    value,
+                    stringify!($pattern $(if $guard)?),
+                ),
+            }
+        };
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum AppError {
+        PageNotFound,
+    }
+
+    type SignalResult<T> = super::SignalResult<T, AppError>;
+
+    #[test]
+    fn test_combine_loading() {
+        let a: SignalResult<HCons<i32, HNil>> = SignalResult::Loading;
+        let b: SignalResult<HCons<i32, HNil>> = SignalResult::Ok(hlist![1]);
+        let result: SignalResult<HCons<i32, HCons<i32, HNil>>> = combine(a, b);
+        assert_matches!(result, SignalResult::Loading);
+
+        let a: SignalResult<HCons<i32, HNil>> = SignalResult::Ok(hlist![1]);
+        let b: SignalResult<HCons<i32, HNil>> = SignalResult::Loading;
+        let result: SignalResult<HCons<i32, HCons<i32, HNil>>> = combine(a, b);
+        assert_matches!(result, SignalResult::Loading);
+    }
+
+    #[test]
+    fn test_combine_ok() {
+        let a = SignalResult::Ok(hlist![1]);
+        let b = SignalResult::Ok(hlist![2.0]);
+        let result: SignalResult<HCons<i32, HCons<f64, HNil>>> = combine(a, b);
+        assert_matches!(result, SignalResult::Ok(_));
+        if let SignalResult::Ok(hlist_pat!(x, y)) = result {
+            assert_eq!(x, 1);
+            assert_eq!(y, 2.0);
+        }
+    }
+
+    #[test]
+    fn test_combine_err() {
+        let a: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        let b: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        let result: SignalResult<HCons<i32, HCons<i32, HNil>>> = combine(a, b);
+        assert_matches!(result, SignalResult::Err(_));
+        if let SignalResult::Err(errors) = result {
+            assert_eq!(errors.len(), 2);
+        }
+
+        let a: SignalResult<HCons<i32, HNil>> = SignalResult::Ok(hlist![1]);
+        let b: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        let result: SignalResult<HCons<i32, HCons<i32, HNil>>> = combine(a, b);
+        assert_matches!(result, SignalResult::Err(_));
+        if let SignalResult::Err(errors) = result {
+            assert_eq!(errors.len(), 1);
+        }
+
+        let a: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        let b: SignalResult<HCons<i32, HNil>> = SignalResult::Ok(hlist![1]);
+        let result: SignalResult<HCons<i32, HCons<i32, HNil>>> = combine(a, b);
+        assert_matches!(result, SignalResult::Err(_));
+        if let SignalResult::Err(errors) = result {
+            assert_eq!(errors.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_combine_dedup_errors() {
+        let a: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        let b: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        let result: SignalResult<HCons<i32, HCons<i32, HNil>>> = combine(a, b).dedup_errors();
+        assert_matches!(result, SignalResult::Err(errors) if errors.len() == 1);
+
+        let a: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        let b: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        let result: SignalResult<HCons<i32, HCons<i32, HNil>>> =
+            combine(a, b).dedup_errors_by(|_| ());
+        assert_matches!(result, SignalResult::Err(errors) if errors.len() == 1);
+    }
+
+    #[test]
+    fn test_combine_reloading() {
+        let a: SignalResult<HCons<i32, HNil>> = SignalResult::Reloading(hlist![1]);
+        let b: SignalResult<HCons<f64, HNil>> = SignalResult::Ok(hlist![2.0]);
+        let result: SignalResult<HCons<i32, HCons<f64, HNil>>> = combine(a, b);
+        assert_matches!(result, SignalResult::Reloading(hlist_pat!(1, _)));
+
+        let a: SignalResult<HCons<i32, HNil>> = SignalResult::Reloading(hlist![1]);
+        let b: SignalResult<HCons<i32, HNil>> = SignalResult::Loading;
+        let result: SignalResult<HCons<i32, HCons<i32, HNil>>> = combine(a, b);
+        assert_matches!(result, SignalResult::Loading);
+
+        let a: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        let b: SignalResult<HCons<i32, HNil>> = SignalResult::Reloading(hlist![1]);
+        let result: SignalResult<HCons<i32, HCons<i32, HNil>>> = combine(a, b);
+        assert_matches!(result, SignalResult::Err(errors) if errors.len() == 1);
+    }
+
+    #[test]
+    fn test_combine_empty() {
+        let a: SignalResult<HCons<Vec<i32>, HNil>> = SignalResult::Ok(hlist![vec![]]);
+        let b: SignalResult<HCons<i32, HNil>> = SignalResult::Reloading(hlist![1]);
+        let result: SignalResult<HCons<Vec<i32>, HCons<i32, HNil>>> =
+            a.empty_when(Vec::is_empty).combine(b);
+        assert_matches!(result, SignalResult::Empty(hlist_pat!(_, 1)));
+
+        let a: SignalResult<HCons<Vec<i32>, HNil>> = SignalResult::Ok(hlist![vec![1]]);
+        let b: SignalResult<HCons<i32, HNil>> = SignalResult::Ok(hlist![1]);
+        let result: SignalResult<HCons<Vec<i32>, HCons<i32, HNil>>> =
+            a.empty_when(Vec::is_empty).combine(b);
+        assert_matches!(result, SignalResult::Ok(_));
+
+        let a: SignalResult<HCons<Vec<i32>, HNil>> = SignalResult::Ok(hlist![vec![]]);
+        let b: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        let result: SignalResult<HCons<Vec<i32>, HCons<i32, HNil>>> =
+            a.empty_when(Vec::is_empty).combine(b);
+        assert_matches!(result, SignalResult::Err(_));
+    }
+
+    #[test]
+    fn test_combine() {
+        let a = SignalResult::Ok(hlist![1]);
+        let b = SignalResult::Ok(hlist![2.0]);
+        let result: SignalResult<HCons<i32, HCons<f64, HNil>>> = a.combine(b);
+        assert_matches!(result, SignalResult::Ok(_));
+        if let SignalResult::Ok(hlist_pat!(x, y)) = result {
+            assert_eq!(x, 1);
+            assert_eq!(y, 2.0);
+        }
+    }
+
+    #[test]
+    fn test_from_option_result() {
+        let ok_result: Option<Result<i32, AppError>> = Some(Ok(42));
+        let err_result: Option<Result<i32, AppError>> = Some(Err(AppError::PageNotFound));
+        let none_result: Option<Result<i32, AppError>> = None;
+
+        assert_matches!(
+            SignalResult::from_option_result(ok_result),
+            SignalResult::Ok(_)
+        );
+        assert_matches!(
+            SignalResult::from_option_result(err_result),
+            SignalResult::Err(_)
+        );
+        assert_matches!(
+            SignalResult::from_option_result(none_result),
+            SignalResult::Loading
+        );
+    }
+
+    #[test]
+    fn test_from_result() {
+        let ok_result: Result<i32, AppError> = Ok(42);
+        let err_result: Result<i32, AppError> = Err(AppError::PageNotFound);
+
+        assert_matches!(SignalResult::from_result(ok_result), SignalResult::Ok(_));
+        assert_matches!(SignalResult::from_result(err_result), SignalResult::Err(_));
+    }
+
+    #[test]
+    fn test_into_ready() {
+        let loading: SignalResult<HCons<i32, HNil>> = SignalResult::Loading;
+        let ok: SignalResult<HCons<i32, HNil>> = SignalResult::Ok(hlist![1]);
+        let reloading: SignalResult<HCons<i32, HNil>> = SignalResult::Reloading(hlist![1]);
+        let err: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+
+        assert_matches!(loading.into_ready(), None);
+        assert_matches!(reloading.into_ready(), Some(Ok(hlist_pat!(1))));
+        assert_matches!(ok.into_ready(), Some(Ok(hlist_pat!(1))));
+        assert_matches!(err.into_ready(), Some(Err(errors)) if errors.len() == 1);
+    }
+
+    #[test]
+    fn test_recover() {
+        let not_found = |e: &AppError| matches!(e, AppError::PageNotFound);
+
+        let err: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        assert_matches!(
+            err.or_else(not_found, || 7),
+            SignalResult::Ok(hlist_pat!(7))
+        );
+
+        let err: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        assert_matches!(err.or_default(not_found), SignalResult::Ok(hlist_pat!(0)));
+
+        let err: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        assert_matches!(err.recover(|_| None), SignalResult::Err(errors) if errors.len() == 1);
+
+        let ok: SignalResult<HCons<i32, HNil>> = SignalResult::Ok(hlist![1]);
+        assert_matches!(ok.or_default(not_found), SignalResult::Ok(hlist_pat!(1)));
+
+        let loading: SignalResult<HCons<i32, HNil>> = SignalResult::Loading;
+        assert_matches!(loading.or_default(not_found), SignalResult::Loading);
+    }
+
+    #[test]
+    fn test_recover_before_combine() {
+        let a: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+        let b: SignalResult<HCons<i32, HNil>> = SignalResult::Ok(hlist![2]);
+        let result: SignalResult<HCons<i32, HCons<i32, HNil>>> = a
+            .or_default(|e| matches!(e, AppError::PageNotFound))
+            .combine(b);
+        assert_matches!(result, SignalResult::Ok(hlist_pat!(0, 2)));
+    }
+
+    #[test]
+    fn test_from_option() {
+        let some_value: Option<i32> = Some(42);
+        let none_value: Option<i32> = None;
+
+        assert_matches!(SignalResult::from_option(some_value), SignalResult::Ok(_));
+        assert_matches!(SignalResult::from_option(none_value), SignalResult::Loading);
+    }
+}
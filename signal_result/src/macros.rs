@@ -0,0 +1,459 @@
+//! This module contains macros for handling signal results with suspense and error handling.
+//!
+//! The macros in this module provide a convenient way to work with types that can be converted
+//! into `SignalResult`, allowing for easy handling of loading states, successful results, and
+//! errors in Leptos views.
+//!
+//! # Macros
+//!
+//! - [`signal_result_view_with_suspense!`]: Creates a view with suspense for handling types
+//!   that can be converted into `SignalResult`, using the loading and error views of the
+//!   [`SignalResultTheme`](crate::SignalResultTheme) in context.
+//! - [`signal_result_view!`]: Creates a view for handling types that can be converted into
+//!   `SignalResult` without suspense.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use signal_result::signal_result_view_with_suspense;
+//!
+//! #[component]
+//! fn MyComponent() -> impl IntoView {
+//!     let data = create_resource(/* ... */);
+//!
+//!     view! {
+//!         {signal_result_view_with_suspense!(
+//!             |data|
+//!             view! {
+//!                 <div>"Data loaded: " {data}</div>
+//!             }
+//!         )}
+//!     }
+//! }
+//! ```
+//!
+//! This example demonstrates the use of `signal_result_view_with_suspense!` to handle
+//! a resource that may be loading, contain an error, or have successfully loaded data.
+//! The `data` parameter is a type that can be converted into `SignalResult`.
+
+#[macro_export]
+/// Creates a view with suspense for handling types that can be converted into `SignalResult`.
+///
+/// This macro simplifies the process of creating a view that handles loading states,
+/// successful results, and errors for types that implement `Into<SignalResult>`. It wraps the content
+/// in the wrapper of the [`SignalResultTheme`](crate::SignalResultTheme) in context and uses the
+/// `signal_result_view!` macro to handle different states.
+///
+/// # Arguments
+///
+/// * `|$($binding),+|` - The bindings passed to the view, as accepted by [`signal_result_view!`].
+/// * `$ok_view:expr` - The view to be rendered when all input types are successfully converted to `SignalResult::Ok`.
+/// * `err($pattern) => $view,` (optional) - Error arms, as accepted by [`signal_result_view!`].
+///   Errors no arm matches are rendered by the theme's error view.
+/// * `$empty_view:expr` (optional) - The view to be rendered when any input is `SignalResult::Empty`.
+///
+/// # Returns
+///
+/// An `AnyView` that handles loading, success, and error states using the wrapper, loading and
+/// error views of the theme returned by [`use_signal_result_theme`](crate::use_signal_result_theme).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// signal_result_view_with_suspense!(|data1, data2|
+///     view! {
+///         <div>"Data loaded: " {data1} ", " {data2}</div>
+///     }
+/// )
+/// ```
+///
+/// Sources can be any expression, bound under another name:
+///
+/// ```rust,ignore
+/// signal_result_view_with_suspense!(|id = params.id, form = form_data|
+///     view! { <FormEditor id form /> }
+/// )
+/// ```
+///
+/// With an empty state:
+///
+/// ```rust,ignore
+/// signal_result_view_with_suspense!(|forms|
+///     view! { <FormTable forms /> },
+///     view! { <EmptyFormList /> }
+/// )
+/// ```
+///
+/// Handling some errors:
+///
+/// ```rust,ignore
+/// signal_result_view_with_suspense!(|form|
+///     view! { <FormEditor form /> },
+///     err(AppError::PageNotFound) => view! { <NotFound /> },
+///     err(AppError::Unauthorized) => redirect(Login),
+/// )
+/// ```
+macro_rules! signal_result_view_with_suspense {
+    (|$($tail:tt)*) => {
+        $crate::signal_result_view_with_suspense!(@bindings [] $($tail)*)
+    };
+    (@bindings [$($bindings:tt)*] | $ok_view:expr $(, $($tail:tt)*)?) => {
+        $crate::signal_result_view_with_suspense!(@arms [$($bindings)*] [$ok_view] [] $($($tail)*)?)
+    };
+    (@bindings [$($bindings:tt)*] $next:tt $($tail:tt)*) => {
+        $crate::signal_result_view_with_suspense!(@bindings [$($bindings)* $next] $($tail)*)
+    };
+    (@bindings [$($bindings:tt)*]) => {
+        ::core::compile_error!("expected `|` after the bindings of `signal_result_view_with_suspense!`")
+    };
+    (@arms [$($bindings:tt)*] [$ok_view:expr] [$($arms:tt)*]
+        err ($($pattern:tt)+) => redirect ($($path:tt)+) $(, $($tail:tt)*)?) => {
+        $crate::signal_result_view_with_suspense!(@arms [$($bindings)*] [$ok_view]
+            [$($arms)* err ($($pattern)+) => redirect ($($path)+),] $($($tail)*)?)
+    };
+    (@arms [$($bindings:tt)*] [$ok_view:expr] [$($arms:tt)*]
+        err ($($pattern:tt)+) => $view:expr $(, $($tail:tt)*)?) => {
+        $crate::signal_result_view_with_suspense!(@arms [$($bindings)*] [$ok_view]
+            [$($arms)* err ($($pattern)+) => $view,] $($($tail)*)?)
+    };
+    // The error and loading views come from the theme.
+    (@arms [$($bindings:tt)*] [$ok_view:expr] [$($arms:tt)*] | $($tail:tt)*) => {
+        $crate::signal_result_view_with_suspense!(@theme_view)
+    };
+    (@arms [$($bindings:tt)*] [$ok_view:expr] [$($arms:tt)*] || $($tail:tt)*) => {
+        $crate::signal_result_view_with_suspense!(@theme_view)
+    };
+    (@arms [$($bindings:tt)*] [$ok_view:expr] [$($arms:tt)*] $empty_view:expr, $($extra:tt)+) => {
+        $crate::signal_result_view_with_suspense!(@theme_view)
+    };
+    (@theme_view) => {
+        ::core::compile_error!(::core::concat!(
+            "`signal_result_view_with_suspense!` only takes a success view, `err` arms and an ",
+            "empty view; the error and loading views come from the `SignalResultTheme` in context"
+        ))
+    };
+    (@arms [$($bindings:tt)*] [$ok_view:expr] [$($arms:tt)*] $($empty_view:expr)? $(,)?) => {{
+        let theme = $crate::use_signal_result_theme();
+        let views = theme.clone();
+        theme.wrap(move || $crate::signal_result_view!(
+            |$($bindings)*| $ok_view,
+            $($arms)*
+            |errors| views.error_view(errors),
+            views.loading_view()
+            $(, $empty_view)?
+        ))
+    }};
+
+    ($($tail:tt)*) => {
+        ::core::compile_error!(
+            "expected `|bindings| success_view` in `signal_result_view_with_suspense!`"
+        )
+    };
+}
+pub use signal_result_view_with_suspense;
+
+#[macro_export]
+/// Creates a view that handles different states of types that can be converted into `SignalResult`.
+///
+/// This macro simplifies the process of creating a view that handles successful results,
+/// errors, and loading states for types that implement `Into<SignalResult>`. It combines multiple
+/// inputs and matches on the combined result to render the appropriate view.
+///
+/// # Arguments
+///
+/// * `|$($binding),+|` - One or more comma-separated bindings, each one of:
+///   - `name`: a variable implementing `Into<SignalResult>`, bound under the same name;
+///   - `name = source`: any expression implementing `Into<SignalResult>`, bound as `name`;
+///   - `_ = source`: a source that must be ready but whose value is not used.
+///
+///   A source stops at the first top-level `,` or `|`; wrap it in parentheses if it contains
+///   either. Binding the same name twice is rejected (`E0416`), `_` may be repeated.
+/// * `$ok_view:expr` - The view to be rendered when all input types are successfully converted to `SignalResult::Ok`.
+///   It is also rendered with the previous values while any input is `SignalResult::Reloading`.
+/// * `err($pattern) => $view,` (any number, optional) - Renders `$view` when any error matches
+///   `$pattern`, whose bindings are usable in `$view`. Arms are tried in order. With
+///   `err($pattern) => redirect($path),` the user is sent to `$path` (any `Display`, such as a
+///   `TypedPath`) through `leptos_router`'s `Redirect` instead; this needs the `router` feature.
+/// * `$error_view:expr` - A closure that takes a `Vec<AppError>` and returns a view for the error state.
+///   It receives the errors of every failing input, so an error shared by several inputs is
+///   repeated; pass them through [`dedup_errors`](crate::dedup_errors) or
+///   [`dedup_errors_by`](crate::dedup_errors_by) to keep one of each. When `err` arms are given,
+///   it only renders errors none of them matched.
+/// * `$loading_view:expr` - The view to be rendered when any input type is converted to `SignalResult::Loading`.
+/// * `$empty_view:expr` (optional) - The view to be rendered when any input is `SignalResult::Empty`.
+///   Without it, empty inputs are rendered with `$ok_view`.
+///
+/// # Returns
+///
+/// An `EitherOf3` enum that implements `ChooseView`, which ultimately renders one of three possible views: success, error, or loading.
+/// When `$empty_view` is given, an `EitherOf4` enum whose fourth view is the empty state.
+/// With `err` arms, the error view is an `AnyView`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// signal_result_view!(
+///     |data1, data2|
+///     view! { <div>"Data loaded: " {data1} ", " {data2}</div> },
+///     |errors| view! { <ErrorComponent errors={errors} /> },
+///     view! { <LoadingSpinner /> }
+/// )
+/// ```
+///
+/// Binding expressions, renaming and ignoring sources:
+///
+/// ```rust,ignore
+/// signal_result_view!(
+///     |id = params.id, data = resource, _ = permissions|
+///     view! { <Form id data /> },
+///     |errors| view! { <ErrorComponent errors={errors} /> },
+///     view! { <LoadingSpinner /> }
+/// )
+/// ```
+///
+/// Matching specific errors:
+///
+/// ```rust,ignore
+/// signal_result_view!(
+///     |form_data|
+///     view! { <FormEvolucao form_data /> },
+///     err(AppError::PageNotFound) => view! { <NotFound /> },
+///     err(AppError::Unauthorized) => redirect(Login),
+///     |errors| view! { <ErrorComponent errors={errors} /> },
+///     view! { <LoadingSpinner /> }
+/// )
+/// ```
+///
+/// Showing each error once, however many inputs failed with it:
+///
+/// ```rust,ignore
+/// signal_result_view!(
+///     |form_data_id, form_data|
+///     view! { <FormEvolucao form_data /> },
+///     |errors| view! { <ErrorComponent errors={dedup_errors(errors)} /> },
+///     view! { <LoadingSpinner /> }
+/// )
+/// ```
+macro_rules! signal_result_view {
+    (|$($tail:tt)*) => {
+        $crate::signal_result_view!(@binding [] $($tail)*)
+    };
+
+    // `name = source` or `_ = source`: collect the source's tokens up to the next `,` or `|`.
+    (@binding [$($done:tt)*] $name:tt = $($tail:tt)*) => {
+        $crate::signal_result_view!(@source [$($done)*] $name [] $($tail)*)
+    };
+    // A bare `name` is its own source. It is matched as a `tt`, which keeps errors about the
+    // source on its span, and checked to be an identifier by `@ident`.
+    (@binding [$($done:tt)*] $name:tt , $($tail:tt)*) => {
+        $crate::signal_result_view!(@ident $name @binding [$($done)* ($name ($name))] $($tail)*)
+    };
+    (@binding [$($done:tt)*] $name:tt | $($tail:tt)*) => {
+        $crate::signal_result_view!(@ident $name @views [$($done)* ($name ($name))] $($tail)*)
+    };
+    // Trailing comma.
+    (@binding [$($done:tt)+] | $($tail:tt)*) => {
+        $crate::signal_result_view!(@views [$($done)+] $($tail)*)
+    };
+    (@binding [$($done:tt)*] $($tail:tt)*) => {
+        $crate::signal_result_view!(@invalid_binding)
+    };
+
+    (@ident $name:ident $($tail:tt)*) => {
+        $crate::signal_result_view!($($tail)*)
+    };
+    (@ident $name:tt $($tail:tt)*) => {
+        $crate::signal_result_view!(@invalid_binding)
+    };
+    (@invalid_binding) => {
+        ::core::compile_error!(
+            "expected `name`, `name = source` or `_ = source` between the `|` of `signal_result_view!`"
+        )
+    };
+
+    (@source [$($done:tt)*] $name:tt [] , $($tail:tt)*) => {
+        $crate::signal_result_view!(@missing_source $name)
+    };
+    (@source [$($done:tt)*] $name:tt [] | $($tail:tt)*) => {
+        $crate::signal_result_view!(@missing_source $name)
+    };
+    (@source [$($done:tt)*] $name:tt [$($source:tt)+] , $($tail:tt)*) => {
+        $crate::signal_result_view!(@binding [$($done)* ($name ($($source)+))] $($tail)*)
+    };
+    (@source [$($done:tt)*] $name:tt [$($source:tt)+] | $($tail:tt)*) => {
+        $crate::signal_result_view!(@views [$($done)* ($name ($($source)+))] $($tail)*)
+    };
+    (@source [$($done:tt)*] $name:tt [$($source:tt)*] $next:tt $($tail:tt)*) => {
+        $crate::signal_result_view!(@source [$($done)*] $name [$($source)* $next] $($tail)*)
+    };
+    (@source [$($done:tt)*] $name:tt [$($source:tt)*]) => {
+        ::core::compile_error!("expected `|` after the bindings of `signal_result_view!`")
+    };
+
+    (@missing_source $name:tt) => {
+        ::core::compile_error!(::core::concat!(
+            "expected a source after `", ::core::stringify!($name), " =` in `signal_result_view!`"
+        ))
+    };
+
+    (@views [$($bindings:tt)*] $ok_view:expr, $($tail:tt)*) => {
+        $crate::__private::unique_bindings!(
+            [$($bindings)*] $crate::signal_result_view!(@arms [$($bindings)*] [$ok_view] [] $($tail)*)
+        )
+    };
+    (@views [$($bindings:tt)*] $($tail:tt)*) => {
+        ::core::compile_error!(
+            "expected a success view, an error view and a loading view after the bindings of `signal_result_view!`"
+        )
+    };
+
+    // `err(pattern) => redirect(path)`: navigate to `path` instead of rendering a view.
+    (@arms [$($bindings:tt)*] [$ok_view:expr] [$($arms:tt)*]
+        err ($($pattern:tt)+) => redirect ($($path:tt)+), $($tail:tt)*) => {
+        $crate::signal_result_view!(@arms [$($bindings)*] [$ok_view] [$($arms)* (
+            ($($pattern)+)
+            ($crate::__private::redirect($($path)+))
+        )] $($tail)*)
+    };
+    // `err(pattern) => view`: render `view` when any error matches `pattern`.
+    (@arms [$($bindings:tt)*] [$ok_view:expr] [$($arms:tt)*]
+        err ($($pattern:tt)+) => $view:expr, $($tail:tt)*) => {
+        $crate::signal_result_view!(@arms [$($bindings)*] [$ok_view] [$($arms)* (($($pattern)+) ($view))] $($tail)*)
+    };
+    (@arms [($first:tt ($($first_source:tt)+)) $(($name:tt ($($source:tt)+)))*] [$ok_view:expr] [$($arms:tt)*]
+        $error_view:expr, $loading_view:expr, $empty_view:expr $(,)?) => {{
+        let validate = $crate::__private::source($($first_source)+)
+            $(.combine($crate::__private::source($($source)+)))*;
+
+        match validate {
+            $crate::SignalResult::Ok(values)
+            | $crate::SignalResult::Reloading(values) => {
+                let $crate::__private::frunk::hlist_pat!($first $(,$name)*) = values;
+                $crate::__private::leptos::either::EitherOf4::A($crate::__private::view($ok_view))
+            },
+            $crate::SignalResult::Err(errors) => {
+                $crate::__private::leptos::either::EitherOf4::B($crate::signal_result_view!(@errors errors, $error_view, [$($arms)*]))
+            },
+            $crate::SignalResult::Loading => {
+                $crate::__private::leptos::either::EitherOf4::C($crate::__private::view($loading_view))
+            },
+            $crate::SignalResult::Empty(_) => {
+                $crate::__private::leptos::either::EitherOf4::D($crate::__private::view($empty_view))
+            }
+        }
+    }};
+    (@arms [($first:tt ($($first_source:tt)+)) $(($name:tt ($($source:tt)+)))*] [$ok_view:expr] [$($arms:tt)*]
+        $error_view:expr, $loading_view:expr $(,)?) => {{
+        let validate = $crate::__private::source($($first_source)+)
+            $(.combine($crate::__private::source($($source)+)))*;
+
+        match validate {
+            $crate::SignalResult::Ok(values)
+            | $crate::SignalResult::Reloading(values)
+            | $crate::SignalResult::Empty(values) => {
+                let $crate::__private::frunk::hlist_pat!($first $(,$name)*) = values;
+                $crate::__private::leptos::either::EitherOf3::A($crate::__private::view($ok_view))
+            },
+            $crate::SignalResult::Err(errors) => {
+                $crate::__private::leptos::either::EitherOf3::B($crate::signal_result_view!(@errors errors, $error_view, [$($arms)*]))
+            },
+            $crate::SignalResult::Loading => {
+                $crate::__private::leptos::either::EitherOf3::C($crate::__private::view($loading_view))
+            }
+        }
+    }};
+
+
+    // Common mistakes, reported here rather than as "no rules expected" errors.
+    (@arms [$($bindings:tt)*] [$ok_view:expr] [$($arms:tt)*] $error_view:expr, err $($tail:tt)*) => {
+        ::core::compile_error!("`err` arms of `signal_result_view!` must come before the error view")
+    };
+    (@arms [$($bindings:tt)*] [$ok_view:expr] [$($arms:tt)*] $(,)?) => {
+        ::core::compile_error!(
+            "expected an error view and a loading view after the success view of `signal_result_view!`"
+        )
+    };
+    (@arms [$($bindings:tt)*] [$ok_view:expr] [$($arms:tt)*] $error_view:expr $(,)?) => {
+        ::core::compile_error!("expected a loading view after the error view of `signal_result_view!`")
+    };
+    (@arms [$($bindings:tt)*] [$ok_view:expr] [$($arms:tt)*]
+        $error_view:expr, $loading_view:expr, $empty_view:expr, $($extra:tt)+) => {
+        ::core::compile_error!("unexpected argument after the empty view of `signal_result_view!`")
+    };
+
+    (@errors $errors:ident, $error_view:expr, []) => {
+        $crate::__private::view($crate::__private::error_view($errors, $error_view))
+    };
+    // The first arm matching any error wins; without a match, every error goes to `$error_view`.
+    (@errors $errors:ident, $error_view:expr, [$((($($pattern:tt)+) ($view:expr)))+]) => {
+        'arms: {
+            $(
+                for error in &$errors {
+                    match ::core::clone::Clone::clone(error) {
+                        $($pattern)+ => break 'arms $crate::__private::leptos::prelude::IntoAny::into_any(
+                            $crate::__private::view($view)
+                        ),
+                        #[allow(unreachable_patterns)]
+                        _ => {}
+                    }
+                }
+            )+
+            $crate::__private::leptos::prelude::IntoAny::into_any(
+                $crate::__private::view($crate::__private::error_view($errors, $error_view))
+            )
+        }
+    };
+
+    ($($tail:tt)*) => {
+        ::core::compile_error!(
+            "expected `|bindings| success_view, error_view, loading_view` in `signal_result_view!`"
+        )
+    };
+}
+
+pub use signal_result_view;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use crate::SignalResult;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum AppError {
+        PageNotFound,
+    }
+
+    #[test]
+    fn test_error_view_receives_every_error() {
+        let a = SignalResult::<_, AppError>::from_result(Err::<i32, _>(AppError::PageNotFound));
+        let b = SignalResult::<_, AppError>::from_result(Err::<i32, _>(AppError::PageNotFound));
+        let received = Mutex::new(Vec::new());
+
+        let _ = signal_result_view!(
+            |a, b| format!("{a} {b}"),
+            |errors| *received.lock().unwrap() = errors,
+            ()
+        );
+
+        assert_eq!(
+            *received.lock().unwrap(),
+            [AppError::PageNotFound, AppError::PageNotFound]
+        );
+    }
+
+    #[test]
+    fn test_error_view_can_dedup_errors() {
+        let a = SignalResult::<_, AppError>::from_result(Err::<i32, _>(AppError::PageNotFound));
+        let b = SignalResult::<_, AppError>::from_result(Err::<i32, _>(AppError::PageNotFound));
+        let received = Mutex::new(Vec::new());
+
+        let _ = signal_result_view!(
+            |a, b| format!("{a} {b}"),
+            |errors| *received.lock().unwrap() = crate::dedup_errors(errors),
+            ()
+        );
+
+        assert_eq!(*received.lock().unwrap(), [AppError::PageNotFound]);
+    }
+}
@@ -0,0 +1,381 @@
+//! Paginated and infinite-list sources for `SignalResult`.
+//!
+//! [`PagedSource`] fetches pages through an async function and accumulates them into a
+//! single `Vec`. The first page drives the usual `SignalResult` states, so it can be fed to
+//! `signal_result_view!`, while later pages report through a separate [`LoadMoreState`].
+
+use std::future::Future;
+use std::sync::Arc;
+
+use futures::future::LocalBoxFuture;
+use futures::FutureExt;
+
+use super::*;
+
+type PageFetcher<T, E> =
+    Arc<dyn Fn(usize) -> LocalBoxFuture<'static, Result<Vec<T>, E>> + Send + Sync>;
+
+/// The state of the pages fetched after the first one.
+#[derive(Debug, Clone)]
+pub enum LoadMoreState<E> {
+    /// Ready to fetch the next page.
+    Idle,
+    /// A page is being fetched.
+    Loading,
+    /// The last page failed. Items from earlier pages are kept, and `load_more` or `retry`
+    /// fetch it again.
+    Failed(E),
+    /// The fetcher returned an empty page, there is nothing left to load.
+    Exhausted,
+}
+
+impl<E> LoadMoreState<E> {
+    /// Whether [`PagedSource::load_more`] would fetch a page in this state.
+    pub fn can_load_more(&self) -> bool {
+        matches!(self, LoadMoreState::Idle | LoadMoreState::Failed(_))
+    }
+}
+
+/// A list source that accumulates pages fetched on demand.
+///
+/// The fetcher receives the zero-based page index and returns the items on that page. An
+/// empty page marks the end of the list.
+///
+/// Converting into a `SignalResult` yields the accumulated items: `Loading` until the
+/// first page arrives, `Err` if the first page fails, and `Ok(items)` afterwards. Failures
+/// of later pages never discard what was already loaded; they are reported through
+/// [`PagedSource::load_more_state`] instead.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let forms = PagedSource::new(|page| list_forms(page, 20));
+///
+/// view! {
+///     {signal_result_view_with_suspense!(|forms| view! { <FormTable forms /> })}
+///     <Show when=move || forms.load_more_state().with(LoadMoreState::can_load_more)>
+///         <button on:click=move |_| forms.load_more()>"Load more"</button>
+///     </Show>
+/// }
+/// ```
+pub struct PagedSource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    fetcher: StoredValue<PageFetcher<T, E>>,
+    first_page: RwSignal<Option<Result<(), E>>>,
+    items: RwSignal<Vec<T>>,
+    next_page: RwSignal<usize>,
+    load_more_state: RwSignal<LoadMoreState<E>>,
+}
+
+impl<T, E> Clone for PagedSource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E> Copy for PagedSource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+}
+
+impl<T, E> PagedSource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    /// Creates the source and immediately fetches the first page.
+    pub fn new<F, Fut>(fetcher: F) -> Self
+    where
+        F: Fn(usize) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<T>, E>> + 'static,
+    {
+        let fetcher: PageFetcher<T, E> = Arc::new(move |page| fetcher(page).boxed_local());
+        let this = Self {
+            fetcher: StoredValue::new(fetcher),
+            first_page: RwSignal::new(None),
+            items: RwSignal::new(Vec::new()),
+            next_page: RwSignal::new(0),
+            load_more_state: RwSignal::new(LoadMoreState::Idle),
+        };
+        this.fetch_next();
+        this
+    }
+
+    /// Fetches the next page, unless a page is already in flight, the list is exhausted or
+    /// the first page has not loaded successfully.
+    ///
+    /// After a failed page this retries the same page. Does nothing once the owner of the
+    /// source has been disposed.
+    pub fn load_more(&self) {
+        let first_page_ok = self
+            .first_page
+            .try_with_untracked(|first_page| matches!(first_page, Some(Ok(()))))
+            .unwrap_or(false);
+        let can_load_more = self
+            .load_more_state
+            .try_with_untracked(LoadMoreState::can_load_more)
+            .unwrap_or(false);
+        if first_page_ok && can_load_more {
+            self.fetch_next();
+        }
+    }
+
+    /// Fetches the page that failed again: the first page, which reports `Loading` until it
+    /// arrives, or the page after the loaded ones when [`LoadMoreState::Failed`].
+    ///
+    /// Does nothing if no page has failed, or once the owner of the source has been disposed.
+    pub fn retry(&self) {
+        let first_page_failed = self
+            .first_page
+            .try_with_untracked(|first_page| matches!(first_page, Some(Err(_))))
+            .unwrap_or(false);
+        let later_page_failed = self
+            .load_more_state
+            .try_with_untracked(|state| matches!(state, LoadMoreState::Failed(_)))
+            .unwrap_or(false);
+        if first_page_failed {
+            self.first_page.set(None);
+            self.fetch_next();
+        } else if later_page_failed {
+            self.fetch_next();
+        }
+    }
+
+    /// The state of the pages after the first one.
+    pub fn load_more_state(&self) -> ReadSignal<LoadMoreState<E>> {
+        self.load_more_state.read_only()
+    }
+
+    /// Returns the accumulated items as a `SignalResult`, tracking them reactively.
+    pub fn get(&self) -> SignalResult<HCons<Vec<T>, HNil>, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        match self.first_page.get() {
+            None => SignalResult::Loading,
+            Some(Err(e)) => SignalResult::Err(vec![e]),
+            Some(Ok(())) => SignalResult::Ok(hlist![self.items.get()]),
+        }
+    }
+
+    fn fetch_next(&self) {
+        let this = *self;
+        let page = this.next_page.get_untracked();
+        let is_first_page = page == 0;
+        if !is_first_page {
+            this.load_more_state.set(LoadMoreState::Loading);
+        }
+
+        let fetch = this.fetcher.with_value(|fetcher| fetcher(page));
+        leptos::task::spawn_local(async move {
+            let result = fetch.await;
+            // The owner may have been disposed while the page was loading.
+            if this.items.is_disposed() {
+                return;
+            }
+            match (is_first_page, result) {
+                (true, Err(e)) => this.first_page.set(Some(Err(e))),
+                (false, Err(e)) => this.load_more_state.set(LoadMoreState::Failed(e)),
+                (_, Ok(items)) => {
+                    let exhausted = items.is_empty();
+                    this.items.update(|all| all.extend(items));
+                    this.next_page.set(page + 1);
+                    if is_first_page {
+                        this.first_page.set(Some(Ok(())));
+                    }
+                    this.load_more_state.set(if exhausted {
+                        LoadMoreState::Exhausted
+                    } else {
+                        LoadMoreState::Idle
+                    });
+                }
+            }
+        });
+    }
+}
+
+impl<H, E> From<PagedSource<H, E>> for SignalResult<HCons<Vec<H>, HNil>, E>
+where
+    H: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + 'static,
+{
+    fn from(value: PagedSource<H, E>) -> Self {
+        value.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use frunk::hlist_pat;
+
+    use super::*;
+    use crate::test_util::{counting_fetcher, settle, with_owner};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum AppError {
+        PageNotFound,
+    }
+
+    /// Serves pages of two items out of `0..len`, failing while `failing` is set.
+    fn pages(len: usize, failing: Arc<AtomicBool>) -> PagedSource<usize, AppError> {
+        PagedSource::new(move |page| {
+            let result = if failing.load(Ordering::SeqCst) {
+                Err(AppError::PageNotFound)
+            } else {
+                Ok((page * 2..len.min(page * 2 + 2)).collect())
+            };
+            std::future::ready(result)
+        })
+    }
+
+    #[tokio::test]
+    async fn test_pages_are_appended_until_exhausted() {
+        with_owner(|_| async {
+            let source = pages(3, Default::default());
+            assert!(matches!(source.get(), SignalResult::Loading));
+            settle().await;
+            assert!(matches!(source.get(), SignalResult::Ok(hlist_pat!(items)) if items == [0, 1]));
+
+            source.load_more();
+            assert!(matches!(
+                source.load_more_state().get(),
+                LoadMoreState::Loading
+            ));
+            settle().await;
+            assert!(
+                matches!(source.get(), SignalResult::Ok(hlist_pat!(items)) if items == [0, 1, 2])
+            );
+            assert!(matches!(
+                source.load_more_state().get(),
+                LoadMoreState::Idle
+            ));
+
+            source.load_more();
+            settle().await;
+            assert!(matches!(
+                source.load_more_state().get(),
+                LoadMoreState::Exhausted
+            ));
+
+            source.load_more();
+            settle().await;
+            assert!(
+                matches!(source.get(), SignalResult::Ok(hlist_pat!(items)) if items == [0, 1, 2])
+            );
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_first_page_error() {
+        with_owner(|_| async {
+            let failing = Arc::new(AtomicBool::new(true));
+            let source = pages(3, Arc::clone(&failing));
+            settle().await;
+            assert!(matches!(source.get(), SignalResult::Err(errors) if errors == [AppError::PageNotFound]));
+
+            failing.store(false, Ordering::SeqCst);
+            source.load_more();
+            settle().await;
+            assert!(matches!(source.get(), SignalResult::Err(_)));
+
+            source.retry();
+            assert!(matches!(source.get(), SignalResult::Loading));
+            settle().await;
+            assert!(matches!(source.get(), SignalResult::Ok(hlist_pat!(items)) if items == [0, 1]));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_retry_later_page() {
+        with_owner(|_| async {
+            let failing = Arc::new(AtomicBool::new(false));
+            let source = pages(3, Arc::clone(&failing));
+            settle().await;
+
+            source.retry();
+            assert!(matches!(
+                source.load_more_state().get(),
+                LoadMoreState::Idle
+            ));
+
+            failing.store(true, Ordering::SeqCst);
+            source.load_more();
+            settle().await;
+            failing.store(false, Ordering::SeqCst);
+            source.retry();
+            assert!(matches!(
+                source.load_more_state().get(),
+                LoadMoreState::Loading
+            ));
+            settle().await;
+            assert!(
+                matches!(source.get(), SignalResult::Ok(hlist_pat!(items)) if items == [0, 1, 2])
+            );
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_later_page_error_keeps_items() {
+        with_owner(|_| async {
+            let failing = Arc::new(AtomicBool::new(false));
+            let source = pages(3, Arc::clone(&failing));
+            settle().await;
+
+            failing.store(true, Ordering::SeqCst);
+            source.load_more();
+            settle().await;
+            assert!(matches!(source.get(), SignalResult::Ok(hlist_pat!(items)) if items == [0, 1]));
+            assert!(matches!(
+                source.load_more_state().get(),
+                LoadMoreState::Failed(AppError::PageNotFound)
+            ));
+
+            failing.store(false, Ordering::SeqCst);
+            source.load_more();
+            settle().await;
+            assert!(
+                matches!(source.get(), SignalResult::Ok(hlist_pat!(items)) if items == [0, 1, 2])
+            );
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_page_loaded_after_disposal() {
+        with_owner(|owner| async move {
+            let (fetches, fetcher) = counting_fetcher::<AppError>(None);
+            let child = owner.child();
+            let source = child.with(|| {
+                PagedSource::new(move |_| {
+                    let fetch = fetcher();
+                    async move { fetch.await.map(|fetch| vec![fetch]) }
+                })
+            });
+            child.cleanup();
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+            source.load_more();
+            source.retry();
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+            assert!(source.load_more_state.try_get_untracked().is_none());
+        })
+        .await;
+    }
+}
@@ -0,0 +1,472 @@
+//! Polling and focus-refetch sources for `SignalResult`.
+//!
+//! [`PollingSource`] keeps a resource fresh without manual reloads: it refetches on an
+//! interval, when the window regains focus and when the network comes back. Interval ticks
+//! are skipped while the tab is hidden, and the source refetches as soon as it is shown again.
+//! Refreshes go through [`ReloadableResource`], so the source reports
+//! `SignalResult::Reloading`, not `Loading`, while they are in flight.
+//!
+//! Timers and browser events are delivered by a [`PollDriver`]. [`BrowserPollDriver`] is used
+//! by default; tests under SSR/native can drive the source with a [`ManualPollDriver`].
+//!
+//! This module needs the `serde` feature, on by default, as Leptos only refetches resources
+//! whose value it can serialize.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::*;
+
+/// An event that may trigger a refetch of a [`PollingSource`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollEvent {
+    /// The polling interval elapsed.
+    Tick,
+    /// The window regained focus.
+    Focus,
+    /// The network connection came back.
+    Online,
+    /// The tab was hidden (`true`) or shown again (`false`), which refetches unless both
+    /// `interval` and `refetch_on_focus` are off.
+    Hidden(bool),
+}
+
+/// Callback receiving the events of a [`PollDriver`].
+pub type PollHandler = Arc<dyn Fn(PollEvent) + Send + Sync>;
+
+/// Delivers timer and browser events to a [`PollingSource`].
+pub trait PollDriver: 'static {
+    /// Starts sending events to `handler` until the current reactive owner is disposed.
+    ///
+    /// `PollEvent::Tick` is only sent when `interval` is set.
+    fn subscribe(&self, interval: Option<Duration>, handler: PollHandler);
+
+    /// Whether the tab is hidden right now, before any `PollEvent::Hidden` was sent.
+    fn is_hidden(&self) -> bool;
+}
+
+/// Drives polling from `setInterval` and the window's `focus`, `online` and
+/// `visibilitychange` events.
+///
+/// Outside the browser (SSR, native tests) it never sends any event.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BrowserPollDriver;
+
+impl PollDriver for BrowserPollDriver {
+    #[cfg(target_arch = "wasm32")]
+    fn subscribe(&self, interval: Option<Duration>, handler: PollHandler) {
+        use leptos::ev;
+        use leptos::prelude::{document, set_interval_with_handle, window_event_listener};
+
+        if let Some(interval) = interval {
+            let tick = Arc::clone(&handler);
+            if let Ok(handle) = set_interval_with_handle(move || tick(PollEvent::Tick), interval) {
+                Owner::on_cleanup(move || handle.clear());
+            }
+        }
+
+        let focus = Arc::clone(&handler);
+        let focus = window_event_listener(ev::focus, move |_| focus(PollEvent::Focus));
+        let online = Arc::clone(&handler);
+        let online = window_event_listener(ev::online, move |_| online(PollEvent::Online));
+        let visibility = window_event_listener(ev::visibilitychange, move |_| {
+            handler(PollEvent::Hidden(document().hidden()))
+        });
+        Owner::on_cleanup(move || {
+            focus.remove();
+            online.remove();
+            visibility.remove();
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn subscribe(&self, _interval: Option<Duration>, _handler: PollHandler) {}
+
+    #[cfg(target_arch = "wasm32")]
+    fn is_hidden(&self) -> bool {
+        leptos::prelude::document().hidden()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn is_hidden(&self) -> bool {
+        false
+    }
+}
+
+/// A driver whose events are sent by hand, for deterministic tests.
+///
+/// Cloning it yields another handle to the same subscribers. Sources subscribed after a
+/// `PollEvent::Hidden` was sent start in the state it reported.
+#[derive(Clone, Default)]
+pub struct ManualPollDriver {
+    handlers: Arc<Mutex<Vec<PollHandler>>>,
+    hidden: Arc<AtomicBool>,
+}
+
+impl ManualPollDriver {
+    /// Sends `event` to every source whose owner is still alive.
+    pub fn send(&self, event: PollEvent) {
+        if let PollEvent::Hidden(hidden) = event {
+            self.hidden.store(hidden, Ordering::Relaxed);
+        }
+        let handlers = self.handlers.lock().expect("poisoned poll driver").clone();
+        for handler in handlers {
+            handler(event);
+        }
+    }
+}
+
+impl PollDriver for ManualPollDriver {
+    fn subscribe(&self, _interval: Option<Duration>, handler: PollHandler) {
+        self.handlers
+            .lock()
+            .expect("poisoned poll driver")
+            .push(Arc::clone(&handler));
+
+        let handlers = Arc::clone(&self.handlers);
+        Owner::on_cleanup(move || {
+            handlers
+                .lock()
+                .expect("poisoned poll driver")
+                .retain(|subscribed| !Arc::ptr_eq(subscribed, &handler));
+        });
+    }
+
+    fn is_hidden(&self) -> bool {
+        self.hidden.load(Ordering::Relaxed)
+    }
+}
+
+/// When a [`PollingSource`] refetches.
+#[derive(Debug, Clone, Copy)]
+pub struct PollOptions {
+    /// Refetch every `interval` while the tab is visible, and when it is shown again. `None`
+    /// disables polling.
+    pub interval: Option<Duration>,
+    /// Refetch when the window regains focus, or the tab is shown again.
+    pub refetch_on_focus: bool,
+    /// Refetch when the network comes back.
+    pub refetch_on_reconnect: bool,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            interval: None,
+            refetch_on_focus: true,
+            refetch_on_reconnect: true,
+        }
+    }
+}
+
+/// A resource that refetches itself on an interval and on focus or reconnect.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let readings = PollingSource::new(
+///     queries::monitor::get_readings(patient_id),
+///     PollOptions {
+///         interval: Some(Duration::from_secs(30)),
+///         ..Default::default()
+///     },
+/// );
+///
+/// signal_result_view_with_suspense!(|readings| view! { <Readings readings /> })
+/// ```
+pub struct PollingSource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    resource: ReloadableResource<T, E>,
+    hidden: RwSignal<bool>,
+}
+
+impl<T, E> Clone for PollingSource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E> Copy for PollingSource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+}
+
+impl<T, E> PollingSource<T, E>
+where
+    T: Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+    E: Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    /// Wraps `resource`, polling it with the [`BrowserPollDriver`].
+    pub fn new(resource: Resource<Result<T, E>>, options: PollOptions) -> Self {
+        Self::with_driver(resource, options, BrowserPollDriver)
+    }
+
+    /// Wraps `resource`, polling it with `driver`.
+    pub fn with_driver(
+        resource: Resource<Result<T, E>>,
+        options: PollOptions,
+        driver: impl PollDriver,
+    ) -> Self {
+        let this = Self {
+            resource: ReloadableResource::new(resource),
+            hidden: RwSignal::new(driver.is_hidden()),
+        };
+
+        driver.subscribe(
+            options.interval,
+            Arc::new(move |event| match event {
+                PollEvent::Tick if options.interval.is_some() && !this.hidden.get_untracked() => {
+                    this.refresh()
+                }
+                PollEvent::Focus if options.refetch_on_focus => this.refresh(),
+                PollEvent::Online if options.refetch_on_reconnect => this.refresh(),
+                PollEvent::Hidden(hidden) => {
+                    let shown = this.hidden.get_untracked() && !hidden;
+                    this.hidden.set(hidden);
+                    if shown && (options.interval.is_some() || options.refetch_on_focus) {
+                        this.refresh();
+                    }
+                }
+                _ => {}
+            }),
+        );
+
+        this
+    }
+
+    /// Whether the tab is currently hidden, pausing interval refetches.
+    pub fn is_paused(&self) -> bool {
+        self.hidden.get()
+    }
+
+    /// Returns the resource's value as a `SignalResult`, tracking it reactively.
+    pub fn get(&self) -> SignalResult<HCons<T, HNil>, E> {
+        self.resource.get()
+    }
+
+    /// Refetches unless a refresh is already in flight.
+    ///
+    /// Ticks, focus, reconnects and invalidation all go through here, so bursts of them only
+    /// start one request.
+    fn refresh(&self) {
+        if !self.resource.is_reloading() {
+            self.resource.reload();
+        }
+    }
+}
+
+impl<T, E> Invalidate for PollingSource<T, E>
+where
+    T: Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+    E: Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn invalidate(&self) {
+        self.refresh();
+    }
+}
+
+impl<H, E> From<PollingSource<H, E>> for SignalResult<HCons<H, HNil>, E>
+where
+    H: Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+    E: Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn from(value: PollingSource<H, E>) -> Self {
+        value.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use frunk::hlist_pat;
+
+    use super::*;
+    use crate::test_util::{counting_fetcher, settle, with_owner};
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    enum AppError {}
+
+    fn counting_source(
+        driver: &ManualPollDriver,
+        options: PollOptions,
+    ) -> (PollingSource<usize, AppError>, Arc<AtomicUsize>) {
+        let (fetches, fetcher) = counting_fetcher(None);
+        let resource = Resource::new(|| (), move |_| fetcher());
+        let source = PollingSource::with_driver(resource, options, driver.clone());
+        (source, fetches)
+    }
+
+    fn every_second() -> PollOptions {
+        PollOptions {
+            interval: Some(Duration::from_secs(1)),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ticks_are_skipped_while_hidden() {
+        with_owner(|_| async {
+            let driver = ManualPollDriver::default();
+            let (source, fetches) = counting_source(&driver, every_second());
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+            driver.send(PollEvent::Tick);
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 2);
+            assert!(matches!(source.get(), SignalResult::Ok(hlist_pat!(1))));
+
+            driver.send(PollEvent::Hidden(true));
+            driver.send(PollEvent::Tick);
+            settle().await;
+            assert!(source.is_paused());
+            assert_eq!(fetches.load(Ordering::SeqCst), 2);
+
+            driver.send(PollEvent::Hidden(false));
+            driver.send(PollEvent::Tick);
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 3);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_refetches_when_shown_again() {
+        with_owner(|_| async {
+            let driver = ManualPollDriver::default();
+            let (source, fetches) = counting_source(&driver, every_second());
+            settle().await;
+
+            driver.send(PollEvent::Hidden(false));
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+            driver.send(PollEvent::Hidden(true));
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+            driver.send(PollEvent::Hidden(false));
+            assert!(matches!(
+                source.get(),
+                SignalResult::Reloading(hlist_pat!(0))
+            ));
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 2);
+            assert!(!source.is_paused());
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_shown_again_follows_options() {
+        with_owner(|_| async {
+            let driver = ManualPollDriver::default();
+            let (_, fetches) = counting_source(
+                &driver,
+                PollOptions {
+                    refetch_on_focus: false,
+                    ..Default::default()
+                },
+            );
+            settle().await;
+
+            driver.send(PollEvent::Hidden(true));
+            driver.send(PollEvent::Hidden(false));
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_starts_paused_when_hidden() {
+        with_owner(|_| async {
+            let driver = ManualPollDriver::default();
+            driver.send(PollEvent::Hidden(true));
+            let (source, fetches) = counting_source(&driver, every_second());
+            settle().await;
+
+            assert!(source.is_paused());
+            driver.send(PollEvent::Tick);
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_focus_and_reconnect_follow_options() {
+        with_owner(|_| async {
+            let driver = ManualPollDriver::default();
+            let (_, fetches) = counting_source(
+                &driver,
+                PollOptions {
+                    refetch_on_reconnect: false,
+                    ..Default::default()
+                },
+            );
+            settle().await;
+
+            driver.send(PollEvent::Tick);
+            driver.send(PollEvent::Online);
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+            driver.send(PollEvent::Focus);
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 2);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_refreshes_in_flight_are_not_repeated() {
+        with_owner(|_| async {
+            let driver = ManualPollDriver::default();
+            let (source, fetches) = counting_source(&driver, every_second());
+            settle().await;
+
+            driver.send(PollEvent::Tick);
+            driver.send(PollEvent::Focus);
+            source.invalidate();
+            assert!(matches!(
+                source.get(),
+                SignalResult::Reloading(hlist_pat!(0))
+            ));
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 2);
+            assert!(matches!(source.get(), SignalResult::Ok(hlist_pat!(1))));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_events_after_disposal_are_dropped() {
+        with_owner(|owner| async move {
+            let driver = ManualPollDriver::default();
+            let child = owner.child();
+            let (_, fetches) = child.with(|| counting_source(&driver, every_second()));
+            settle().await;
+
+            child.cleanup();
+            driver.send(PollEvent::Hidden(true));
+            driver.send(PollEvent::Tick);
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        })
+        .await;
+    }
+}
@@ -0,0 +1,697 @@
+//! A client-side query cache producing `SignalResult` sources.
+//!
+//! Every component creating its own `Resource` means that navigating back to a page refetches
+//! everything. [`QueryCache`] shares fetched values between components instead:
+//!
+//! - **Dedup**: reading a key that is already being fetched reuses the in-flight request.
+//! - **Stale time**: values younger than [`QueryOptions::stale_time`] are served without
+//!   refetching. Older values are still served, but refreshed in the background.
+//! - **GC**: entries nobody reads for longer than [`QueryOptions::gc_time`] are dropped.
+//! - **Invalidation**: [`QueryCache::invalidate`] marks an entry stale and refetches it.
+//!
+//! While an entry with a value is being refetched, its readers see `SignalResult::Reloading`.
+//! Errors are never fresh: the next read of a failed entry fetches it again.
+//!
+//! Entries are keyed by a [`QueryKey`], built from a path (such as a `TypedPath`) or any `Hash` key.
+//! Time is read through a [`Clock`], so tests can drive staleness and GC with a
+//! [`ManualClock`].
+
+use std::any::{Any, TypeId};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Display};
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::*;
+
+/// Identifies an entry in a [`QueryCache`].
+///
+/// Keys keep the value they were built from, so two keys are only equal when their values
+/// are, even if their hashes collide.
+#[derive(Clone)]
+pub struct QueryKey {
+    hash: u64,
+    key: Arc<dyn KeyValue>,
+}
+
+impl QueryKey {
+    /// Creates a key from any hashable value.
+    ///
+    /// The type of `key` is part of the key, so `QueryKey::new(1u32)` and
+    /// `QueryKey::new(1u64)` are different keys.
+    pub fn new<K>(key: K) -> Self
+    where
+        K: Hash + Eq + Debug + Send + Sync + 'static,
+    {
+        let mut hasher = DefaultHasher::new();
+        TypeId::of::<K>().hash(&mut hasher);
+        key.hash(&mut hasher);
+        Self {
+            hash: hasher.finish(),
+            key: Arc::new(key),
+        }
+    }
+
+    /// Creates a key from a path, such as a `TypedPath`, using the URL it renders to.
+    pub fn from_path<P>(path: &P) -> Self
+    where
+        P: Display + 'static,
+    {
+        Self::new((TypeId::of::<P>(), path.to_string()))
+    }
+}
+
+impl PartialEq for QueryKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.key.eq_key(other.key.as_any())
+    }
+}
+
+impl Eq for QueryKey {}
+
+impl Hash for QueryKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+impl Debug for QueryKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("QueryKey").field(&self.key).finish()
+    }
+}
+
+/// The value a [`QueryKey`] was built from, compared through `Any`.
+trait KeyValue: Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn eq_key(&self, other: &dyn Any) -> bool;
+}
+
+impl<K> KeyValue for K
+where
+    K: Eq + Debug + Send + Sync + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_key(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<K>() == Some(self)
+    }
+}
+
+/// A source of the current time for a [`QueryCache`].
+pub trait Clock: Send + Sync + 'static {
+    /// Time elapsed since an arbitrary, fixed origin.
+    fn now(&self) -> Duration;
+}
+
+/// The wall clock, used by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[cfg(target_arch = "wasm32")]
+    fn now(&self) -> Duration {
+        Duration::from_secs_f64(js_sys::Date::now() / 1000.0)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn now(&self) -> Duration {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+}
+
+/// A clock that only moves when told to, for deterministic tests.
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock(Arc<AtomicU64>);
+
+impl ManualClock {
+    /// Moves the clock forward by `by`.
+    pub fn advance(&self, by: Duration) {
+        self.0.fetch_add(by.as_millis() as u64, Ordering::SeqCst);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        Duration::from_millis(self.0.load(Ordering::SeqCst))
+    }
+}
+
+/// Timing options for a [`QueryCache`].
+#[derive(Debug, Clone, Copy)]
+pub struct QueryOptions {
+    /// How long a fetched value is considered fresh.
+    pub stale_time: Duration,
+    /// How long an entry with no readers is kept before being dropped.
+    pub gc_time: Duration,
+}
+
+impl Default for QueryOptions {
+    fn default() -> Self {
+        Self {
+            stale_time: Duration::ZERO,
+            gc_time: Duration::from_secs(5 * 60),
+        }
+    }
+}
+
+struct Entry {
+    /// An `ArcRwSignal<Option<Result<T, E>>>` for the entry's value and error types.
+    value: Box<dyn Any + Send + Sync>,
+    reloading: ArcRwSignal<bool>,
+    refetch: Arc<dyn Fn() + Send + Sync>,
+    updated_at: Option<Duration>,
+    in_flight: bool,
+    readers: usize,
+    last_read: Duration,
+}
+
+impl Entry {
+    fn is_stale(&self, now: Duration, stale_time: Duration) -> bool {
+        match self.updated_at {
+            Some(updated_at) => now.saturating_sub(updated_at) >= stale_time,
+            None => true,
+        }
+    }
+
+    fn is_collectable(&self, now: Duration, gc_time: Duration) -> bool {
+        self.readers == 0 && !self.in_flight && now.saturating_sub(self.last_read) >= gc_time
+    }
+}
+
+type Entries = HashMap<(QueryKey, TypeId), Entry>;
+
+/// A shared cache of fetched values. See the [module documentation](self) for details.
+///
+/// Cloning a `QueryCache` yields another handle to the same entries. Provide one near the
+/// root of the app with [`provide_query_cache`] and read it with [`use_query_cache`].
+#[derive(Clone)]
+pub struct QueryCache {
+    entries: Arc<Mutex<Entries>>,
+    clock: Arc<dyn Clock>,
+    options: QueryOptions,
+}
+
+impl Default for QueryCache {
+    fn default() -> Self {
+        Self::new(QueryOptions::default())
+    }
+}
+
+impl QueryCache {
+    /// Creates an empty cache using the [`SystemClock`].
+    pub fn new(options: QueryOptions) -> Self {
+        Self::with_clock(options, SystemClock)
+    }
+
+    /// Creates an empty cache reading time from `clock`.
+    pub fn with_clock(options: QueryOptions, clock: impl Clock) -> Self {
+        Self {
+            entries: Default::default(),
+            clock: Arc::new(clock),
+            options,
+        }
+    }
+
+    /// Reads `key` from the cache, fetching it with `fetcher` if it is missing or stale.
+    ///
+    /// The returned [`Query`] belongs to the current reactive owner; the entry counts as
+    /// read until that owner is disposed. Without an owner, the entry only counts as read
+    /// now, and is dropped once unread for [`QueryOptions::gc_time`].
+    pub fn query<T, E, F, Fut>(&self, key: QueryKey, fetcher: F) -> Query<T, E>
+    where
+        T: Clone + Send + Sync + 'static,
+        E: Clone + Send + Sync + 'static,
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, E>> + 'static,
+    {
+        let now = self.clock.now();
+        // A reader is only released by the cleanup of its owner, which can't run without one.
+        let owned = Owner::current().is_some();
+        let id = (key, TypeId::of::<Result<T, E>>());
+        let mut entries = self.lock();
+        Self::collect_garbage(&mut entries, now, self.options.gc_time);
+
+        let entry = entries.entry(id.clone()).or_insert_with(|| {
+            let value = ArcRwSignal::new(None::<Result<T, E>>);
+            let reloading = ArcRwSignal::new(false);
+            Entry {
+                refetch: self.refetcher(id.clone(), value.clone(), reloading.clone(), fetcher),
+                value: Box::new(value),
+                reloading,
+                updated_at: None,
+                in_flight: false,
+                readers: 0,
+                last_read: now,
+            }
+        });
+        if owned {
+            entry.readers += 1;
+        }
+        entry.last_read = now;
+
+        let value = entry
+            .value
+            .downcast_ref::<ArcRwSignal<Option<Result<T, E>>>>()
+            .expect("query entries are keyed by value and error types")
+            .clone();
+        let reloading = entry.reloading.clone();
+        let refetch = (!entry.in_flight && entry.is_stale(now, self.options.stale_time))
+            .then(|| Arc::clone(&entry.refetch));
+        drop(entries);
+
+        if let Some(refetch) = refetch {
+            refetch();
+        }
+
+        if owned {
+            let cache = self.clone();
+            Owner::on_cleanup(move || {
+                let now = cache.clock.now();
+                if let Some(entry) = cache.lock().get_mut(&id) {
+                    entry.readers -= 1;
+                    entry.last_read = now;
+                }
+            });
+        }
+
+        Query {
+            value: value.into(),
+            reloading: reloading.into(),
+        }
+    }
+
+    /// Marks every entry under `key` stale and refetches it.
+    pub fn invalidate(&self, key: &QueryKey) {
+        self.invalidate_where(|k| k == key);
+    }
+
+    /// Marks every entry stale and refetches it.
+    pub fn invalidate_all(&self) {
+        self.invalidate_where(|_| true);
+    }
+
+    /// Drops entries that have had no readers for longer than [`QueryOptions::gc_time`].
+    ///
+    /// This also runs on every [`QueryCache::query`].
+    pub fn gc(&self) {
+        let now = self.clock.now();
+        Self::collect_garbage(&mut self.lock(), now, self.options.gc_time);
+    }
+
+    /// Whether the cache holds an entry for `key`.
+    pub fn contains(&self, key: &QueryKey) -> bool {
+        self.lock().keys().any(|(k, _)| k == key)
+    }
+
+    fn invalidate_where(&self, mut matches: impl FnMut(&QueryKey) -> bool) {
+        let refetches = self
+            .lock()
+            .iter_mut()
+            .filter(|((key, _), _)| matches(key))
+            .filter_map(|(_, entry)| {
+                entry.updated_at = None;
+                (!entry.in_flight).then(|| Arc::clone(&entry.refetch))
+            })
+            .collect::<Vec<_>>();
+
+        for refetch in refetches {
+            refetch();
+        }
+    }
+
+    fn refetcher<T, E, F, Fut>(
+        &self,
+        id: (QueryKey, TypeId),
+        value: ArcRwSignal<Option<Result<T, E>>>,
+        reloading: ArcRwSignal<bool>,
+        fetcher: F,
+    ) -> Arc<dyn Fn() + Send + Sync>
+    where
+        T: Send + Sync + 'static,
+        E: Send + Sync + 'static,
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, E>> + 'static,
+    {
+        let entries = Arc::downgrade(&self.entries);
+        let clock = Arc::clone(&self.clock);
+        Arc::new(move || {
+            let Some(cache) = entries.upgrade() else {
+                return;
+            };
+            if let Some(entry) = cache.lock().expect("poisoned query cache").get_mut(&id) {
+                entry.in_flight = true;
+            }
+            reloading.set(true);
+
+            let fetch = fetcher();
+            let id = id.clone();
+            let entries = entries.clone();
+            let clock = Arc::clone(&clock);
+            let value = value.clone();
+            let reloading = reloading.clone();
+            leptos::task::spawn_local(async move {
+                let result = fetch.await;
+                if let Some(cache) = entries.upgrade() {
+                    let mut entries = cache.lock().expect("poisoned query cache");
+                    if let Some(entry) = entries.get_mut(&id) {
+                        entry.in_flight = false;
+                        entry.updated_at = result.is_ok().then(|| clock.now());
+                    }
+                }
+                value.set(Some(result));
+                reloading.set(false);
+            });
+        })
+    }
+
+    fn collect_garbage(entries: &mut Entries, now: Duration, gc_time: Duration) {
+        entries.retain(|_, entry| !entry.is_collectable(now, gc_time));
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries> {
+        self.entries.lock().expect("poisoned query cache")
+    }
+}
+
+/// Provides `cache` to the current component and its children.
+pub fn provide_query_cache(cache: QueryCache) {
+    provide_context(cache);
+}
+
+/// Returns the [`QueryCache`] provided by an ancestor.
+///
+/// # Panics
+///
+/// Panics if no cache was provided with [`provide_query_cache`].
+pub fn use_query_cache() -> QueryCache {
+    expect_context()
+}
+
+/// A cached value read from a [`QueryCache`].
+///
+/// While a refetch is in flight, the previous value is kept and reported as
+/// `SignalResult::Reloading`, so the source only reports `Loading` before the first fetch
+/// completes.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let path = SomeParameterPath::new("test".to_string());
+/// let data = use_query_cache().query(QueryKey::from_path(&path), move || get_data(path.clone()));
+///
+/// signal_result_view_with_suspense!(|data| view! { <Data data /> })
+/// ```
+pub struct Query<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    value: Signal<Option<Result<T, E>>>,
+    reloading: Signal<bool>,
+}
+
+impl<T, E> Clone for Query<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E> Copy for Query<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+}
+
+impl<T, E> Query<T, E>
+where
+    T: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + 'static,
+{
+    /// Returns the cached value as a `SignalResult`, tracking it reactively.
+    pub fn get(&self) -> SignalResult<HCons<T, HNil>, E> {
+        match self.value.get() {
+            Some(Ok(t)) if self.reloading.get() => SignalResult::Reloading(hlist![t]),
+            value => SignalResult::from_option_result(value),
+        }
+    }
+}
+
+impl<H, E> From<Query<H, E>> for SignalResult<HCons<H, HNil>, E>
+where
+    H: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + 'static,
+{
+    fn from(value: Query<H, E>) -> Self {
+        value.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use frunk::hlist_pat;
+
+    use super::*;
+    use crate::test_util::{counting_fetcher, settle, with_owner};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum AppError {
+        PageNotFound,
+    }
+
+    fn entry(updated_at: Option<Duration>, readers: usize, last_read: Duration) -> Entry {
+        Entry {
+            value: Box::new(()),
+            reloading: ArcRwSignal::new(false),
+            refetch: Arc::new(|| {}),
+            updated_at,
+            in_flight: false,
+            readers,
+            last_read,
+        }
+    }
+
+    fn cache(stale_secs: u64, gc_secs: u64) -> (QueryCache, ManualClock) {
+        let clock = ManualClock::default();
+        let options = QueryOptions {
+            stale_time: Duration::from_secs(stale_secs),
+            gc_time: Duration::from_secs(gc_secs),
+        };
+        (QueryCache::with_clock(options, clock.clone()), clock)
+    }
+
+    #[test]
+    fn test_query_key() {
+        assert_eq!(QueryKey::new("a"), QueryKey::new("a"));
+        assert_ne!(QueryKey::new("a"), QueryKey::new("b"));
+        assert_ne!(QueryKey::new(1u32), QueryKey::new(1u64));
+    }
+
+    #[test]
+    fn test_query_key_hash_collision() {
+        let a = QueryKey {
+            hash: 0,
+            key: Arc::new("a"),
+        };
+        let b = QueryKey {
+            hash: 0,
+            key: Arc::new("b"),
+        };
+        assert_ne!(a, b);
+    }
+
+    #[tokio::test]
+    async fn test_query_dedups_fetches() {
+        with_owner(|_| async {
+            let (cache, _) = cache(10, 60);
+            let (fetches, fetcher) = counting_fetcher::<AppError>(None);
+            let fetcher = Arc::new(fetcher);
+
+            let first = cache.query(QueryKey::new("user"), {
+                let fetcher = Arc::clone(&fetcher);
+                move || fetcher()
+            });
+            let second = cache.query(QueryKey::new("user"), move || fetcher());
+            assert!(matches!(first.get(), SignalResult::Loading));
+            settle().await;
+
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+            assert!(matches!(first.get(), SignalResult::Ok(hlist_pat!(0))));
+            assert!(matches!(second.get(), SignalResult::Ok(hlist_pat!(0))));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_query_refetches_when_stale() {
+        with_owner(|_| async {
+            let (cache, clock) = cache(10, 60);
+            let (fetches, fetcher) = counting_fetcher::<AppError>(None);
+            let fetcher = Arc::new(fetcher);
+            let read = || {
+                let fetcher = Arc::clone(&fetcher);
+                cache.query(QueryKey::new("user"), move || fetcher())
+            };
+            read();
+            settle().await;
+
+            clock.advance(Duration::from_secs(9));
+            assert!(matches!(read().get(), SignalResult::Ok(hlist_pat!(0))));
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+            clock.advance(Duration::from_secs(1));
+            let query = read();
+            assert!(matches!(
+                query.get(),
+                SignalResult::Reloading(hlist_pat!(0))
+            ));
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 2);
+            assert!(matches!(query.get(), SignalResult::Ok(hlist_pat!(1))));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_query_errors_are_not_fresh() {
+        with_owner(|_| async {
+            let (cache, _) = cache(10, 60);
+            let (fetches, fetcher) = counting_fetcher(Some(AppError::PageNotFound));
+            let fetcher = Arc::new(fetcher);
+            let read = || {
+                let fetcher = Arc::clone(&fetcher);
+                cache.query(QueryKey::new("user"), move || fetcher())
+            };
+            let query = read();
+            settle().await;
+            assert!(matches!(query.get(), SignalResult::Err(_)));
+
+            read();
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 2);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_query_gc_keeps_read_entries() {
+        with_owner(|owner| async move {
+            let (cache, clock) = cache(10, 60);
+            let (_, fetcher) = counting_fetcher::<AppError>(None);
+            let key = QueryKey::new("user");
+            let reader = owner.child();
+            reader.with(|| cache.query(key.clone(), fetcher));
+            settle().await;
+
+            clock.advance(Duration::from_secs(120));
+            cache.gc();
+            assert!(cache.contains(&key));
+
+            reader.cleanup();
+            clock.advance(Duration::from_secs(59));
+            cache.gc();
+            assert!(cache.contains(&key));
+
+            clock.advance(Duration::from_secs(1));
+            cache.gc();
+            assert!(!cache.contains(&key));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_query_gc_without_owner() {
+        with_owner(|owner| async move {
+            let (cache, clock) = cache(10, 60);
+            let (_, fetcher) = counting_fetcher::<AppError>(None);
+            let key = QueryKey::new("user");
+            owner.clone().unset();
+            cache.query(key.clone(), fetcher);
+            owner.set();
+            settle().await;
+
+            clock.advance(Duration::from_secs(59));
+            cache.gc();
+            assert!(cache.contains(&key));
+
+            clock.advance(Duration::from_secs(1));
+            cache.gc();
+            assert!(!cache.contains(&key));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_query_invalidate() {
+        with_owner(|_| async {
+            let (cache, _) = cache(10, 60);
+            let (fetches, fetcher) = counting_fetcher::<AppError>(None);
+            let key = QueryKey::new("user");
+            let query = cache.query(key.clone(), fetcher);
+            settle().await;
+
+            cache.invalidate(&key);
+            assert!(matches!(
+                query.get(),
+                SignalResult::Reloading(hlist_pat!(0))
+            ));
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 2);
+            assert!(matches!(query.get(), SignalResult::Ok(hlist_pat!(1))));
+
+            cache.invalidate(&QueryKey::new("other"));
+            settle().await;
+            assert_eq!(fetches.load(Ordering::SeqCst), 2);
+        })
+        .await;
+    }
+
+    #[test]
+    fn test_manual_clock() {
+        let clock = ManualClock::default();
+        assert_eq!(clock.now(), Duration::ZERO);
+        clock.clone().advance(Duration::from_secs(3));
+        assert_eq!(clock.now(), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_entry_staleness() {
+        let stale_time = Duration::from_secs(10);
+        let fetched = entry(Some(Duration::from_secs(5)), 1, Duration::ZERO);
+
+        assert!(!fetched.is_stale(Duration::from_secs(14), stale_time));
+        assert!(fetched.is_stale(Duration::from_secs(15), stale_time));
+        assert!(entry(None, 1, Duration::ZERO).is_stale(Duration::ZERO, stale_time));
+    }
+
+    #[test]
+    fn test_collect_garbage() {
+        let gc_time = Duration::from_secs(60);
+        let mut entries = Entries::new();
+        let unread = (QueryKey::new("unread"), TypeId::of::<()>());
+        let read = (QueryKey::new("read"), TypeId::of::<()>());
+        entries.insert(unread.clone(), entry(None, 0, Duration::ZERO));
+        entries.insert(read.clone(), entry(None, 1, Duration::ZERO));
+
+        QueryCache::collect_garbage(&mut entries, Duration::from_secs(59), gc_time);
+        assert_eq!(entries.len(), 2);
+
+        QueryCache::collect_garbage(&mut entries, Duration::from_secs(60), gc_time);
+        assert!(!entries.contains_key(&unread));
+        assert!(entries.contains_key(&read));
+    }
+}
@@ -0,0 +1,232 @@
+//! Deduplicated, labeled error reports.
+//!
+//! When several sources fail for the same reason, `combine` yields the same error once per
+//! source. [`ErrorReport`] merges them instead: errors sharing a key become a single
+//! [`ReportedError`] that counts its occurrences and keeps the labels of the sources it
+//! came from.
+//!
+//! Keys are computed the same way as for [`SignalResult::dedup_errors_by`]: a function
+//! from an error to any `PartialEq` value, [`debug_key`] by default.
+//!
+//! The view macros and `SignalResultTheme` only see the combined `SignalResult`, whose
+//! sources carry no labels, so their error views receive a plain `Vec<E>` holding an error once
+//! per source that returned it. [`dedup_errors`] keeps one of each. To show which sources
+//! failed, build a report from the individual sources before combining them and render it
+//! yourself.
+
+use super::*;
+
+/// The default key errors are deduplicated by: errors are equal when their `Debug` output
+/// is.
+///
+/// This tells variants apart, including their payloads.
+pub fn debug_key<E>(error: &E) -> String
+where
+    E: std::fmt::Debug,
+{
+    format!("{error:?}")
+}
+
+/// Removes repeated errors, comparing them with [`debug_key`].
+///
+/// See [`dedup_errors_by`].
+pub fn dedup_errors<E>(errors: Vec<E>) -> Vec<E>
+where
+    E: std::fmt::Debug,
+{
+    dedup_errors_by(errors, debug_key)
+}
+
+/// Removes errors whose `key` was already seen, keeping the first of each.
+///
+/// The view macros pass the errors of every failing source to their error view; an error view
+/// can call this to render an error shared by several sources only once.
+///
+/// # Examples
+///
+/// ```rust
+/// use signal_result::dedup_errors_by;
+///
+/// let errors = dedup_errors_by(vec!["not found", "forbidden", "not found"], |error| *error);
+/// assert_eq!(errors, ["not found", "forbidden"]);
+/// ```
+pub fn dedup_errors_by<E, K>(errors: Vec<E>, key: impl Fn(&E) -> K) -> Vec<E>
+where
+    K: PartialEq,
+{
+    let mut seen = Vec::with_capacity(errors.len());
+    errors
+        .into_iter()
+        .filter(|error| {
+            let key = key(error);
+            let is_new = !seen.contains(&key);
+            if is_new {
+                seen.push(key);
+            }
+            is_new
+        })
+        .collect()
+}
+
+/// An error merged from one or more sources.
+#[derive(Debug, Clone)]
+pub struct ReportedError<E> {
+    /// The first error reported under this key.
+    pub error: E,
+    /// How many times an error with this key was reported.
+    pub count: usize,
+    /// The labels of the sources reporting it, in order and without repetition.
+    pub sources: Vec<&'static str>,
+}
+
+/// Errors from several sources, merged by the key `K` computed for each of them.
+///
+/// # Examples
+///
+/// ```rust
+/// use signal_result::{ErrorReport, SignalResult};
+/// # #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// # enum AppError { PageNotFound }
+/// use frunk::HList;
+///
+/// let form_data_id = SignalResult::<HList!(i32), _>::Err(vec![AppError::PageNotFound]);
+/// let form_data = SignalResult::<HList!(String), _>::Err(vec![AppError::PageNotFound]);
+///
+/// let mut report = ErrorReport::default();
+/// report.add("form_data_id", &form_data_id);
+/// report.add("form_data", &form_data);
+///
+/// let [entry] = report.entries() else { panic!() };
+/// assert_eq!(entry.count, 2);
+/// assert_eq!(entry.sources, ["form_data_id", "form_data"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ErrorReport<E, K = String> {
+    key: fn(&E) -> K,
+    /// The key of each entry, at the same index.
+    keys: Vec<K>,
+    entries: Vec<ReportedError<E>>,
+}
+
+impl<E> Default for ErrorReport<E>
+where
+    E: Clone + std::fmt::Debug,
+{
+    fn default() -> Self {
+        Self::with_key(debug_key)
+    }
+}
+
+impl<E, K> ErrorReport<E, K>
+where
+    E: Clone,
+    K: PartialEq,
+{
+    /// Creates an empty report merging errors by `key`.
+    pub fn with_key(key: fn(&E) -> K) -> Self {
+        Self {
+            key,
+            keys: Vec::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Reports `error` as coming from `source`.
+    pub fn push(&mut self, source: &'static str, error: E) {
+        let key = (self.key)(&error);
+        match self.keys.iter().position(|existing| *existing == key) {
+            Some(index) => {
+                let entry = &mut self.entries[index];
+                entry.count += 1;
+                if !entry.sources.contains(&source) {
+                    entry.sources.push(source);
+                }
+            }
+            None => {
+                self.keys.push(key);
+                self.entries.push(ReportedError {
+                    error,
+                    count: 1,
+                    sources: vec![source],
+                });
+            }
+        }
+    }
+
+    /// Reports the errors of `result`, if any, as coming from `source`.
+    pub fn add<T>(&mut self, source: &'static str, result: &SignalResult<T, E>)
+    where
+        T: HList,
+    {
+        if let SignalResult::Err(errors) = result {
+            for error in errors {
+                self.push(source, error.clone());
+            }
+        }
+    }
+
+    /// The merged errors, in the order they were first reported.
+    pub fn entries(&self) -> &[ReportedError<E>] {
+        &self.entries
+    }
+
+    /// Whether no error was reported.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// One error per key, in the order they were first reported.
+    pub fn errors(&self) -> Vec<E> {
+        self.entries
+            .iter()
+            .map(|entry| entry.error.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frunk::{HCons, HNil};
+
+    #[derive(Debug, Clone)]
+    enum AppError {
+        PageNotFound,
+    }
+
+    type SignalResult<T> = super::SignalResult<T, AppError>;
+
+    #[test]
+    fn test_report_merges_by_key() {
+        let mut report = ErrorReport::default();
+        report.push("a", AppError::PageNotFound);
+        report.push("b", AppError::PageNotFound);
+        report.push("b", AppError::PageNotFound);
+
+        assert_eq!(report.entries().len(), 1);
+        assert_eq!(report.entries()[0].count, 3);
+        assert_eq!(report.entries()[0].sources, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_report_custom_key() {
+        let mut report = ErrorReport::with_key(|_| ());
+        report.push("a", AppError::PageNotFound);
+        report.push("b", AppError::PageNotFound);
+
+        assert_eq!(report.errors().len(), 1);
+    }
+
+    #[test]
+    fn test_report_add() {
+        let ok: SignalResult<HCons<i32, HNil>> = SignalResult::Ok(frunk::hlist![1]);
+        let err: SignalResult<HCons<i32, HNil>> = SignalResult::Err(vec![AppError::PageNotFound]);
+
+        let mut report = ErrorReport::default();
+        report.add("ok", &ok);
+        assert!(report.is_empty());
+
+        report.add("err", &err);
+        assert_eq!(report.entries()[0].sources, ["err"]);
+    }
+}
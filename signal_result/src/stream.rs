@@ -0,0 +1,174 @@
+//! Stream-backed sources for `SignalResult`.
+//!
+//! Some data (monitor readings, notifications, ...) arrives as a stream rather than as a
+//! one-shot `Resource`. [`StreamSource`] drives a `futures::Stream` in the background and
+//! exposes its latest item through a signal, so it can be combined with the other sources.
+
+use futures::future::{AbortHandle, Abortable};
+use futures::{Stream, StreamExt};
+
+use super::*;
+
+/// A reactive source fed by a `Stream<Item = Result<T, E>>`.
+///
+/// - Before the first item arrives it converts to `SignalResult::Loading`.
+/// - Afterwards it reflects the latest item, `Ok` or `Err`.
+/// - When the stream ends, the last item is kept, so a stream that ends with an error
+///   stays in the `Err` state.
+///
+/// The stream is polled on the local executor and is dropped when the current reactive
+/// owner is disposed.
+///
+/// # Examples
+///
+/// ```rust
+/// use signal_result::{SignalResult, StreamSource};
+/// # #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// # enum AppError { PageNotFound }
+/// use frunk::hlist_pat;
+/// use futures::channel::mpsc;
+/// use leptos::prelude::*;
+/// # tokio_test::block_on(async move {
+/// # tokio::task::LocalSet::new().run_until(async move {
+/// # let _ = leptos::task::Executor::init_tokio();
+///
+/// let (readings_tx, readings_rx) = mpsc::unbounded();
+/// let readings = StreamSource::new(readings_rx);
+/// let threshold = Memo::new(|_| Ok::<_, AppError>(2));
+///
+/// let combined = move || SignalResult::from(readings).combine(SignalResult::from(threshold));
+/// assert!(matches!(combined(), SignalResult::Loading));
+///
+/// readings_tx.unbounded_send(Ok(1)).unwrap();
+/// # tokio::task::yield_now().await;
+/// assert!(matches!(combined(), SignalResult::Ok(hlist_pat!(1, 2))));
+/// # });
+/// # });
+/// ```
+pub struct StreamSource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    value: RwSignal<Option<Result<T, E>>>,
+}
+
+impl<T, E> Clone for StreamSource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E> Copy for StreamSource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+}
+
+impl<T, E> StreamSource<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    /// Starts polling `stream`, storing every item it yields.
+    ///
+    /// Server function streams and websocket messages usually carry their own error type;
+    /// map them into the application's error type before handing them over:
+    ///
+    /// ```rust,ignore
+    /// let notifications = StreamSource::new(socket.map(|msg| msg.map_err(AppError::from)));
+    /// ```
+    pub fn new<S>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<T, E>> + 'static,
+    {
+        let value = RwSignal::new(None);
+
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
+        let task = stream.for_each(move |item| {
+            value.set(Some(item));
+            async {}
+        });
+        leptos::task::spawn_local(async move {
+            let _ = Abortable::new(task, abort_registration).await;
+        });
+        Owner::on_cleanup(move || abort_handle.abort());
+
+        Self { value }
+    }
+
+    /// Returns the latest item as a `SignalResult`, tracking it reactively.
+    pub fn get(&self) -> SignalResult<HCons<T, HNil>, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        SignalResult::from_option_result(self.value.get())
+    }
+}
+
+impl<H, E> From<StreamSource<H, E>> for SignalResult<HCons<H, HNil>, E>
+where
+    H: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + 'static,
+{
+    fn from(value: StreamSource<H, E>) -> Self {
+        value.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use frunk::hlist_pat;
+    use futures::channel::mpsc;
+
+    use super::*;
+    use crate::test_util::{settle, with_owner};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum AppError {
+        PageNotFound,
+    }
+
+    #[tokio::test]
+    async fn test_stream_states() {
+        with_owner(|_| async {
+            let (tx, rx) = mpsc::unbounded();
+            let source = StreamSource::new(rx);
+            settle().await;
+            assert!(matches!(source.get(), SignalResult::Loading));
+
+            tx.unbounded_send(Ok(1)).unwrap();
+            settle().await;
+            assert!(matches!(source.get(), SignalResult::Ok(hlist_pat!(1))));
+
+            tx.unbounded_send(Err(AppError::PageNotFound)).unwrap();
+            drop(tx);
+            settle().await;
+            assert!(
+                matches!(source.get(), SignalResult::Err(errors) if errors == [AppError::PageNotFound])
+            );
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_stream_is_dropped_with_its_owner() {
+        with_owner(|owner| async move {
+            let (tx, rx) = mpsc::unbounded::<Result<i32, AppError>>();
+            let child = owner.child();
+            child.with(|| StreamSource::new(rx));
+            settle().await;
+
+            child.cleanup();
+            settle().await;
+            assert!(tx.unbounded_send(Ok(1)).is_err());
+        })
+        .await;
+    }
+}
@@ -0,0 +1,50 @@
+//! Helpers for the tests of the reactive sources.
+
+use std::future::{Future, Ready};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use leptos::prelude::Owner;
+
+/// Runs `test` on a local task set with an owner, as a component would. The owner, and the
+/// context provided to it, live until `test` completes.
+pub(crate) async fn with_owner<F>(test: impl FnOnce(Owner) -> F)
+where
+    F: Future<Output = ()>,
+{
+    let _ = any_spawner::Executor::init_tokio();
+    let owner = Owner::new();
+    owner.set();
+    tokio::task::LocalSet::new()
+        .run_until(test(owner.clone()))
+        .await;
+}
+
+/// Lets spawned fetches and the tasks waiting on them run to completion.
+pub(crate) async fn settle() {
+    for _ in 0..10 {
+        tokio::task::yield_now().await;
+    }
+}
+
+/// A fetcher counting its calls, returning the number of earlier calls or `error`.
+pub(crate) fn counting_fetcher<E>(
+    error: Option<E>,
+) -> (
+    Arc<AtomicUsize>,
+    impl Fn() -> Ready<Result<usize, E>> + Send + Sync + 'static,
+)
+where
+    E: Clone + Send + Sync + 'static,
+{
+    let fetches = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&fetches);
+    let fetcher = move || {
+        let fetch = counter.fetch_add(1, Ordering::SeqCst);
+        std::future::ready(match &error {
+            Some(error) => Err(error.clone()),
+            None => Ok(fetch),
+        })
+    };
+    (fetches, fetcher)
+}
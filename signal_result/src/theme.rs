@@ -0,0 +1,233 @@
+//! Default views used by the `SignalResult` macros and `SignalResultBoundary`.
+//!
+//! [`signal_result_view_with_suspense!`](crate::signal_result_view_with_suspense) only receives
+//! the success (and empty) views; the suspense wrapper, the loading view and the error view come
+//! from the [`SignalResultTheme`] in context. Without one, [`SignalResultTheme::default`] is
+//! used, which only depends on Leptos itself.
+//!
+//! A theme is usually provided once near the root of the app, and can be overridden for a
+//! subtree with `SignalResultBoundary` (with the `components` feature):
+//!
+//! ```rust,ignore
+//! provide_signal_result_theme(
+//!     SignalResultTheme::default()
+//!         .with_wrapper(|_fallback, content| view! { <SuspenseSkeleton>{content}</SuspenseSkeleton> })
+//!         .with_loading(|| view! { <Skeleton /> })
+//!         .with_error(|errors| view! { <ErrorReporter errors /> }),
+//! );
+//! ```
+
+use std::sync::Arc;
+
+use super::*;
+
+type Wrapper = Arc<dyn Fn(ViewFn, AnyView) -> AnyView + Send + Sync>;
+type ErrorView<E> = Arc<dyn Fn(Vec<E>) -> AnyView + Send + Sync>;
+
+/// The wrapper, loading and error views rendered by default for `SignalResult` sources with
+/// errors of type `E`.
+pub struct SignalResultTheme<E> {
+    wrapper: Wrapper,
+    loading: ViewFn,
+    error: ErrorView<E>,
+}
+
+impl<E> Clone for SignalResultTheme<E> {
+    fn clone(&self) -> Self {
+        Self {
+            wrapper: Arc::clone(&self.wrapper),
+            loading: self.loading.clone(),
+            error: Arc::clone(&self.error),
+        }
+    }
+}
+
+impl<E> Default for SignalResultTheme<E>
+where
+    E: std::fmt::Debug,
+{
+    /// Wraps content in a `<Suspense>` falling back to the loading view, renders nothing while
+    /// loading and shows errors with their `Debug` output: as a list with the `components`
+    /// feature, as plain text separated by `; ` without it.
+    fn default() -> Self {
+        Self {
+            wrapper: Arc::new(|fallback, content| {
+                view! { <Suspense fallback=move || fallback.run()>{content}</Suspense> }.into_any()
+            }),
+            loading: ViewFn::default(),
+            error: Arc::new(|errors| default_error_view(errors)),
+        }
+    }
+}
+
+/// The error view of the default theme, listing errors with their `Debug` output.
+#[cfg(feature = "components")]
+fn default_error_view<E>(errors: Vec<E>) -> AnyView
+where
+    E: std::fmt::Debug,
+{
+    view! {
+        <ul class="signal-result-errors">
+            {errors
+                .into_iter()
+                .map(|error| view! { <li>{format!("{error:?}")}</li> })
+                .collect_view()}
+        </ul>
+    }
+    .into_any()
+}
+
+/// The error view of the default theme, writing errors as text with their `Debug` output.
+#[cfg(not(feature = "components"))]
+fn default_error_view<E>(errors: Vec<E>) -> AnyView
+where
+    E: std::fmt::Debug,
+{
+    error_text(&errors).into_any()
+}
+
+/// The text rendered by the default error view without the `components` feature.
+#[cfg(not(feature = "components"))]
+fn error_text<E>(errors: &[E]) -> String
+where
+    E: std::fmt::Debug,
+{
+    errors
+        .iter()
+        .map(|error| format!("{error:?}"))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl<E> SignalResultTheme<E> {
+    /// Sets the component wrapping every view, such as a `<Suspense>`.
+    ///
+    /// `wrapper` receives the loading view, to be used as a fallback, and the content.
+    pub fn with_wrapper<V>(
+        mut self,
+        wrapper: impl Fn(ViewFn, AnyView) -> V + Send + Sync + 'static,
+    ) -> Self
+    where
+        V: IntoView + 'static,
+    {
+        self.wrapper = Arc::new(move |fallback, content| wrapper(fallback, content).into_any());
+        self
+    }
+
+    /// Sets the view rendered while any source is loading.
+    pub fn with_loading(mut self, loading: impl Into<ViewFn>) -> Self {
+        self.loading = loading.into();
+        self
+    }
+
+    /// Sets the view rendered when any source failed.
+    pub fn with_error<V>(mut self, error: impl Fn(Vec<E>) -> V + Send + Sync + 'static) -> Self
+    where
+        V: IntoView + 'static,
+    {
+        self.error = Arc::new(move |errors| error(errors).into_any());
+        self
+    }
+
+    /// Wraps `content` in this theme's wrapper.
+    pub fn wrap(&self, content: impl IntoView + 'static) -> AnyView {
+        (self.wrapper)(self.loading.clone(), content.into_any())
+    }
+
+    /// Renders the loading view.
+    pub fn loading_view(&self) -> AnyView {
+        self.loading.run()
+    }
+
+    /// Renders the error view for `errors`.
+    pub fn error_view(&self, errors: Vec<E>) -> AnyView {
+        (self.error)(errors)
+    }
+}
+
+/// Provides `theme` to the current component and its children.
+pub fn provide_signal_result_theme<E>(theme: SignalResultTheme<E>)
+where
+    E: 'static,
+{
+    provide_context(theme);
+}
+
+/// Returns the closest provided [`SignalResultTheme`] for errors of type `E`, or the default one.
+pub fn use_signal_result_theme<E>() -> SignalResultTheme<E>
+where
+    E: 'static,
+    SignalResultTheme<E>: Default,
+{
+    use_context().unwrap_or_default()
+}
+
+/// Provides `theme` to `children` and renders them inside its wrapper.
+///
+/// `theme` replaces the provided theme for `children` and their descendants.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let theme = SignalResultTheme::<AppError>::default().with_loading(|| view! { <Spinner /> });
+///
+/// view! {
+///     <SignalResultBoundary theme>
+///         {signal_result_view_with_suspense!(|forms| view! { <FormTable forms /> })}
+///     </SignalResultBoundary>
+/// }
+/// ```
+#[cfg(feature = "components")]
+#[component]
+pub fn SignalResultBoundary<E>(
+    /// The theme used by this boundary and everything inside it.
+    theme: SignalResultTheme<E>,
+    children: Children,
+) -> impl IntoView
+where
+    E: Send + Sync + 'static,
+{
+    provide_signal_result_theme(theme.clone());
+    theme.wrap(children())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    enum AppError {
+        PageNotFound,
+    }
+
+    #[tokio::test]
+    async fn test_theme_from_context() {
+        crate::test_util::with_owner(|_| async {
+            provide_signal_result_theme(
+                SignalResultTheme::<AppError>::default()
+                    .with_wrapper(|_fallback, content| view! { <section>{content}</section> })
+                    .with_loading(|| "loading")
+                    .with_error(|errors: Vec<AppError>| format!("{} errors", errors.len())),
+            );
+
+            let failed = Memo::new(|_| Err::<i32, _>(AppError::PageNotFound));
+            let html = crate::signal_result_view_with_suspense!(|failed| failed).to_html();
+            assert_eq!(html, "<section>1 errors</section>");
+
+            let pending = Resource::new(|| (), |_| std::future::pending::<Result<i32, AppError>>());
+            let html = crate::signal_result_view_with_suspense!(|pending| pending).to_html();
+            assert_eq!(html, "<section>loading</section>");
+        })
+        .await;
+    }
+
+    #[cfg(not(feature = "components"))]
+    #[test]
+    fn test_default_error_view_lists_errors_as_text() {
+        assert_eq!(
+            error_text(&["not found", "forbidden"]),
+            r#""not found"; "forbidden""#
+        );
+        assert_eq!(error_text::<()>(&[]), "");
+    }
+}
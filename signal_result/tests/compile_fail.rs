@@ -0,0 +1,18 @@
+//! Snapshots of the errors reported for common mistakes with the view macros and
+//! `#[signal_result]`, and uses of them that must keep compiling.
+//!
+//! Run with `TRYBUILD=overwrite` to update the `.stderr` files after changing a diagnostic.
+//!
+//! Some diagnostics list the sources `SignalResult` converts from, which depend on the
+//! enabled features, so the snapshots are only checked with the default features.
+
+#[test]
+#[cfg_attr(
+    not(all(feature = "components", feature = "serde", not(feature = "router"))),
+    ignore = "the snapshots are recorded with the default features"
+)]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    t.pass("tests/pass/*.rs");
+}
@@ -0,0 +1,16 @@
+use leptos::prelude::*;
+use signal_result::signal_result_view;
+
+#[derive(Debug, Clone, PartialEq)]
+enum AppError {}
+
+fn main() {
+    let form = Memo::new(|_| Ok::<_, AppError>(1));
+    let user = Memo::new(|_| Ok::<_, AppError>("Ada"));
+    let count = Memo::new(|_| Ok::<_, AppError>(2));
+    let _ = signal_result_view!(
+        |_ = user, _ = count, form| view! { <p>{form}</p> },
+        |_errors: Vec<AppError>| "error",
+        "loading"
+    );
+}
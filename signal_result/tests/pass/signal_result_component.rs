@@ -0,0 +1,16 @@
+use leptos::prelude::*;
+use signal_result::signal_result;
+
+#[derive(Debug, Clone, PartialEq)]
+enum AppError {}
+
+#[signal_result(error = AppError)]
+fn Greeting(#[source] name: String, #[prop(optional)] greeting: String) -> impl IntoView {
+    view! { <p>{greeting} ", " {name}</p> }
+}
+
+fn main() {
+    let name = Memo::new(|_| Ok::<_, AppError>(String::from("Ada")));
+    let _ = view! { <Greeting name greeting="Hello".to_owned() /> };
+    let _ = view! { <Greeting name /> };
+}
@@ -0,0 +1,12 @@
+use signal_result::signal_result_view;
+
+#[derive(Debug, Clone, PartialEq)]
+enum AppError {}
+
+fn main() {
+    let _ = signal_result_view!(
+        |"form"| "form",
+        |_errors: Vec<AppError>| "error",
+        "loading"
+    );
+}
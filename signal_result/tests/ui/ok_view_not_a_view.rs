@@ -0,0 +1,22 @@
+use leptos::prelude::*;
+use signal_result::signal_result_view;
+
+#[derive(Debug, Clone, PartialEq)]
+enum AppError {}
+
+struct Form;
+
+#[component]
+fn FormView(form: Memo<Result<i32, AppError>>) -> impl IntoView {
+    view! {
+        <div>
+            {move || signal_result_view!(
+                |form| { let _ = form; Form },
+                |_errors: Vec<AppError>| "error",
+                "loading"
+            )}
+        </div>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use signal_result::signal_result;
+
+#[derive(Debug, Clone, PartialEq)]
+enum AppError {}
+
+#[signal_result(error = AppError)]
+#[component]
+fn Greeting(#[source] name: String) -> impl IntoView {
+    view! { <p>{name}</p> }
+}
+
+fn main() {}
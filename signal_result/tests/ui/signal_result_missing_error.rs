@@ -0,0 +1,8 @@
+use signal_result::signal_result;
+
+#[signal_result]
+fn Greeting(#[source] name: String) -> impl IntoView {
+    view! { <p>{name}</p> }
+}
+
+fn main() {}
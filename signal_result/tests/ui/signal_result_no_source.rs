@@ -0,0 +1,11 @@
+use signal_result::signal_result;
+
+#[derive(Debug, Clone, PartialEq)]
+enum AppError {}
+
+#[signal_result(error = AppError)]
+fn Greeting(name: String) -> impl IntoView {
+    view! { <p>{name}</p> }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use signal_result::signal_result;
+
+#[derive(Debug, Clone, PartialEq)]
+enum AppError {}
+
+#[signal_result(error = AppError)]
+fn Greeting(#[source] (first, last): (String, String)) -> impl IntoView {
+    view! { <p>{first} {last}</p> }
+}
+
+fn main() {}
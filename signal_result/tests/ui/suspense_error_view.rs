@@ -0,0 +1,13 @@
+use leptos::prelude::*;
+use signal_result::signal_result_view_with_suspense;
+
+#[derive(Debug, Clone, PartialEq)]
+enum AppError {}
+
+fn main() {
+    let form = Memo::new(|_| Ok::<_, AppError>(1));
+    let _ = signal_result_view_with_suspense!(
+        |form| view! { <p>{form}</p> },
+        |_errors: Vec<AppError>| "error"
+    );
+}
@@ -3,13 +3,64 @@ use quote::{quote, ToTokens};
 use syn::parse::Parse;
 
 mod attr_parsing;
+mod path_param;
 mod typed_path;
 
+/// Derives `TypedPath`, `Display` and `ToHref` from a `#[typed_path("/users/:id")]` pattern.
+///
+/// Captures are bound to the fields of the same name, or to tuple fields in order, and are
+/// formatted and parsed with `PathParam`. Named fields without a capture must be `PhantomData`
+/// markers or marked `#[typed_path(skip)]`, and are set to their `Default` when parsing.
+///
+/// A single optional `:name?` capture can end the path. It binds an `Option` field, whose `None`
+/// omits the segment. Formatting an empty `Some` value fails, as it would parse back as `None`.
+///
+/// A `*rest` wildcard can end the path. It captures the remaining segments into a `WildcardParam`
+/// such as `Vec<String>` or `PathBuf`, each segment being percent-encoded on its own.
+///
+/// With the `ssr` feature, typed paths also implement axum's `FromRequestParts`, rejecting
+/// requests with their `FromPath::Rejection` when it implements `IntoResponse`.
+///
+/// Structs and enums can be generic. Type parameters used by a captured field must implement
+/// `PathParam`, and those used by other fields `Default`.
+///
+/// On an enum, each variant takes its own `#[typed_path]`, and the first variant matching a path
+/// is the one parsed. `TypedPath::PATHS` lists the path of every variant, `TypedPath::PATH` being
+/// the first.
+///
+/// `<TypedRoute<P> view=... />` works with any typed path, and gives each of its paths a route
+/// rendering the same view. Enum variants can instead name the component rendering them. When
+/// every variant has a `view(...)`, and only then, an inherent `Routes` function registers all of
+/// them inside `<Routes>`:
+///
+/// ```rust,ignore
+/// #[derive(TypedPath)]
+/// pub enum AppRoute {
+///     #[typed_path("/", view(HomePage))]
+///     Home,
+///     #[typed_path("/users/:id", view(UserPage))]
+///     User { id: u32 },
+/// }
+///
+/// view! {
+///     <Routes fallback=|| "Not found.">
+///         <AppRoute::Routes />
+///     </Routes>
+/// }
+/// ```
 #[proc_macro_derive(TypedPath, attributes(typed_path))]
 pub fn derive_typed_path(input: TokenStream) -> TokenStream {
     expand_with(input, typed_path::expand)
 }
 
+/// Derives `PathParam` for a newtype, delegating to its field, or for an enum with unit variants.
+///
+/// Variants are written in kebab-case, unless renamed with `#[path_param("...")]`.
+#[proc_macro_derive(PathParam, attributes(path_param))]
+pub fn derive_path_param(input: TokenStream) -> TokenStream {
+    expand_with(input, path_param::expand)
+}
+
 fn expand_with<F, I, K>(input: TokenStream, f: F) -> TokenStream
 where
     F: FnOnce(I) -> syn::Result<K>,
@@ -0,0 +1,160 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Fields, LitStr};
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    match &input.data {
+        Data::Struct(data) => expand_newtype(&input, &data.fields),
+        Data::Enum(data) => expand_unit_variants(&input, data),
+        Data::Union(_) => Err(syn::Error::new_spanned(
+            &input.ident,
+            "`#[derive(PathParam)]` doesn't support unions",
+        )),
+    }
+}
+
+/// Delegates to the only field of the struct.
+fn expand_newtype(input: &DeriveInput, fields: &Fields) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+
+    let mut fields = fields.iter();
+    let field = match (fields.next(), fields.next()) {
+        (Some(field), None) => field,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "`#[derive(PathParam)]` on structs needs exactly one field",
+            ))
+        }
+    };
+
+    let ty = &field.ty;
+    let (member, construct) = match &field.ident {
+        Some(name) => (quote! { #name }, quote! { |value| Self { #name: value } }),
+        None => (quote! { 0 }, quote! { Self }),
+    };
+
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! { #ty: crate::routes::path_param::PathParam });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics crate::routes::path_param::PathParam for #ident #ty_generics
+        #where_clause
+        {
+            type Err = <#ty as crate::routes::path_param::PathParam>::Err;
+
+            fn to_segment(&self) -> ::std::borrow::Cow<'_, str> {
+                crate::routes::path_param::PathParam::to_segment(&self.#member)
+            }
+
+            fn from_segment(segment: &str) -> ::std::result::Result<Self, Self::Err> {
+                crate::routes::path_param::PathParam::from_segment(segment).map(#construct)
+            }
+        }
+    })
+}
+
+/// Writes each variant as its kebab-case name, or the name given by `#[path_param("...")]`.
+fn expand_unit_variants(input: &DeriveInput, data: &syn::DataEnum) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`#[derive(PathParam)]` doesn't support generic enums",
+        ));
+    }
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "`#[derive(PathParam)]` needs at least one variant",
+        ));
+    }
+
+    let mut variants = Vec::new();
+    let mut segments: Vec<LitStr> = Vec::new();
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                &variant.fields,
+                "`#[derive(PathParam)]` only supports enums with unit variants",
+            ));
+        }
+
+        let mut rename = None;
+        for attr in &variant.attrs {
+            if attr.path().is_ident("path_param") {
+                if rename.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "`path_param` specified more than once",
+                    ));
+                }
+                rename = Some(attr.parse_args::<LitStr>()?);
+            }
+        }
+
+        let segment = rename.unwrap_or_else(|| {
+            LitStr::new(
+                &kebab_case(&variant.ident.to_string()),
+                variant.ident.span(),
+            )
+        });
+        if segment.value().is_empty() || segment.value().contains('/') {
+            return Err(syn::Error::new_spanned(
+                segment,
+                "path params can't be empty or contain a `/`",
+            ));
+        }
+        if let Some(previous) = segments.iter().find(|s| s.value() == segment.value()) {
+            let mut err = syn::Error::new_spanned(
+                &segment,
+                format!("`{}` is used by more than one variant", segment.value()),
+            );
+            err.combine(syn::Error::new_spanned(previous, "first used here"));
+            return Err(err);
+        }
+
+        variants.push(&variant.ident);
+        segments.push(segment);
+    }
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl crate::routes::path_param::PathParam for #ident {
+            type Err = crate::routes::path_param::UnknownVariant;
+
+            fn to_segment(&self) -> ::std::borrow::Cow<'_, str> {
+                ::std::borrow::Cow::Borrowed(match self {
+                    #(Self::#variants => #segments,)*
+                })
+            }
+
+            fn from_segment(segment: &str) -> ::std::result::Result<Self, Self::Err> {
+                match segment {
+                    #(#segments => ::std::result::Result::Ok(Self::#variants),)*
+                    _ => ::std::result::Result::Err(crate::routes::path_param::UnknownVariant {
+                        expected: &[#(#segments),*],
+                    }),
+                }
+            }
+        }
+    })
+}
+
+fn kebab_case(ident: &str) -> String {
+    let mut out = String::with_capacity(ident.len() + 4);
+    for (i, c) in ident.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            out.push('-');
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
@@ -1,10 +1,23 @@
-use proc_macro2::{Span, TokenStream};
-use quote::{format_ident, quote, quote_spanned};
-use syn::{parse::Parse, ItemStruct, LitStr, Token};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    parse::Parse, parse_quote, spanned::Spanned, Ident, Item, ItemEnum, ItemStruct, LitStr, Token,
+};
 
 use crate::attr_parsing::{combine_attribute, parse_parenthesized_attribute, second, Combine};
 
-pub(crate) fn expand(item_struct: ItemStruct) -> syn::Result<TokenStream> {
+pub(crate) fn expand(item: Item) -> syn::Result<TokenStream> {
+    match item {
+        Item::Struct(item_struct) => expand_struct(item_struct),
+        Item::Enum(item_enum) => expand_enum(item_enum),
+        item => Err(syn::Error::new_spanned(
+            item,
+            "`#[derive(TypedPath)]` only supports structs and enums",
+        )),
+    }
+}
+
+fn expand_struct(item_struct: ItemStruct) -> syn::Result<TokenStream> {
     let ItemStruct {
         attrs,
         ident,
@@ -13,14 +26,11 @@ pub(crate) fn expand(item_struct: ItemStruct) -> syn::Result<TokenStream> {
         ..
     } = &item_struct;
 
-    if !generics.params.is_empty() || generics.where_clause.is_some() {
-        return Err(syn::Error::new_spanned(
-            generics,
-            "`#[derive(TypedPath)]` doesn't support generics",
-        ));
-    }
-
-    let Attrs { path, rejection } = crate::attr_parsing::parse_attrs("typed_path", attrs)?;
+    let Attrs {
+        path,
+        rejection,
+        view,
+    } = crate::attr_parsing::parse_attrs("typed_path", attrs)?;
 
     let path = path.ok_or_else(|| {
         syn::Error::new(
@@ -29,35 +39,206 @@ pub(crate) fn expand(item_struct: ItemStruct) -> syn::Result<TokenStream> {
         )
     })?;
 
+    if let Some((kw, _)) = view {
+        return Err(syn::Error::new_spanned(
+            kw,
+            "`view` is only supported on enum variants",
+        ));
+    }
+
     let rejection = rejection.map(second);
+    let from_request_parts = from_request_parts_impl(ident, generics);
 
-    match fields {
-        syn::Fields::Named(_) => {
+    let impls = match fields {
+        syn::Fields::Named(fields) => {
             let segments = parse_path(&path)?;
-            Ok(expand_named_fields(ident, path, &segments, rejection))
+            let generics =
+                StructGenerics::new(generics, field_segments(&item_struct.fields, &segments));
+            expand_named_fields(fields, ident, &generics, path, &segments, rejection)?
         }
         syn::Fields::Unnamed(fields) => {
             let segments = parse_path(&path)?;
-            expand_unnamed_fields(fields, ident, path, &segments, rejection)
+            let generics =
+                StructGenerics::new(generics, field_segments(&item_struct.fields, &segments));
+            expand_unnamed_fields(fields, ident, &generics, path, &segments, rejection)?
+        }
+        syn::Fields::Unit => {
+            let generics = StructGenerics::new(generics, std::iter::empty());
+            expand_unit_fields(ident, &generics, path, rejection)?
+        }
+    };
+
+    Ok(quote! {
+        #impls
+        #from_request_parts
+    })
+}
+
+/// Implements axum's `FromRequestParts` with `FromPath::from_path`, with the `ssr` feature.
+fn from_request_parts_impl(ident: &Ident, generics: &syn::Generics) -> TokenStream {
+    if !cfg!(feature = "ssr") {
+        return TokenStream::new();
+    }
+
+    let state = Ident::new("S", Span::mixed_site());
+    let rejection = Ident::new("R", Span::mixed_site());
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let mut impl_generics = generics.clone();
+    impl_generics.params.push(parse_quote! { #state });
+    impl_generics.params.push(parse_quote! { #rejection });
+    // Naming the rejection as a parameter defers its `IntoResponse` bound to the uses of the impl,
+    // so paths whose rejection isn't a response still compile and only can't be extracted.
+    impl_generics.make_where_clause().predicates.extend([
+        parse_quote! { #state: ::std::marker::Send + ::std::marker::Sync },
+        parse_quote! {
+            #ident #ty_generics: crate::routes::typed_path::FromPath<Rejection = #rejection>
+        },
+        parse_quote! { #rejection: ::axum::response::IntoResponse },
+    ] as [syn::WherePredicate; 3]);
+    let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        #[::axum::async_trait]
+        impl #impl_generics ::axum::extract::FromRequestParts<#state> for #ident #ty_generics
+        #where_clause
+        {
+            type Rejection = #rejection;
+
+            async fn from_request_parts(
+                parts: &mut ::axum::http::request::Parts,
+                _state: &#state,
+            ) -> ::std::result::Result<Self, Self::Rejection> {
+                // Typed paths are absolute, while `Router::nest` strips its prefix from `parts.uri`.
+                let uri = parts
+                    .extensions
+                    .get::<::axum::extract::OriginalUri>()
+                    .map_or(&parts.uri, |uri| &uri.0);
+                let path = uri.path_and_query().map_or("/", |path| path.as_str());
+                <Self as crate::routes::typed_path::FromPath>::from_path(path)
+            }
+        }
+    }
+}
+
+/// The generics of the impls derived for a struct or an enum.
+///
+/// The bounds declared on the type are kept. Fields whose type mentions a type parameter get
+/// the bounds the generated code needs: captures are formatted and parsed with `PathParam`, or
+/// `WildcardParam` for wildcards, and other fields are set to their `Default` when parsing.
+struct StructGenerics {
+    /// For `Display` and `ToHref`.
+    display: syn::Generics,
+    /// For `TypedPath`.
+    typed_path: syn::Generics,
+}
+
+impl StructGenerics {
+    fn new<'a>(
+        generics: &syn::Generics,
+        fields: impl IntoIterator<Item = (&'a syn::Type, Option<&'a Segment>)>,
+    ) -> Self {
+        let params: Vec<_> = generics
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect();
+
+        let mut display = generics.clone();
+        let mut typed_path = generics.clone();
+
+        for (ty, segment) in fields {
+            if !mentions_any(ty.to_token_stream(), &params) {
+                continue;
+            }
+
+            let bound: syn::WherePredicate = match segment {
+                Some(Segment::Capture(..)) => {
+                    parse_quote! { #ty: crate::routes::path_param::PathParam }
+                }
+                Some(Segment::Optional(..)) => {
+                    parse_quote! { #ty: crate::routes::path_param::OptionalParam }
+                }
+                Some(Segment::Wildcard(..)) => {
+                    parse_quote! { #ty: crate::routes::path_param::WildcardParam }
+                }
+                Some(Segment::Static(_)) | None => {
+                    typed_path
+                        .make_where_clause()
+                        .predicates
+                        .push(parse_quote! { #ty: ::std::default::Default });
+                    continue;
+                }
+            };
+            display.make_where_clause().predicates.push(bound.clone());
+            typed_path.make_where_clause().predicates.push(bound);
+        }
+
+        Self {
+            display,
+            typed_path,
         }
-        syn::Fields::Unit => expand_unit_fields(ident, path, rejection),
     }
 }
 
+/// Pairs each field with the segment capturing it: by name for named fields, in order for tuple
+/// fields.
+fn field_segments<'a>(
+    fields: &'a syn::Fields,
+    segments: &'a [Segment],
+) -> Vec<(&'a syn::Type, Option<&'a Segment>)> {
+    match fields {
+        syn::Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                let segment = segments.iter().find(|segment| {
+                    segment.capture().is_some_and(|(capture, _)| {
+                        field.ident.as_ref().is_some_and(|ident| ident == capture)
+                    })
+                });
+                (&field.ty, segment)
+            })
+            .collect(),
+        syn::Fields::Unnamed(fields) => fields
+            .unnamed
+            .iter()
+            .zip(
+                segments
+                    .iter()
+                    .filter(|segment| !matches!(segment, Segment::Static(_))),
+            )
+            .map(|(field, segment)| (&field.ty, Some(segment)))
+            .collect(),
+        syn::Fields::Unit => Vec::new(),
+    }
+}
+
+fn mentions_any(tokens: TokenStream, idents: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&ident),
+        TokenTree::Group(group) => mentions_any(group.stream(), idents),
+        TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+    })
+}
+
 mod kw {
     syn::custom_keyword!(rejection);
+    syn::custom_keyword!(skip);
+    syn::custom_keyword!(view);
 }
 
 #[derive(Default)]
 struct Attrs {
     path: Option<LitStr>,
     rejection: Option<(kw::rejection, syn::Path)>,
+    view: Option<(kw::view, syn::Expr)>,
 }
 
 impl Parse for Attrs {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let mut path = None;
         let mut rejection = None;
+        let mut view = None;
 
         while !input.is_empty() {
             let lh = input.lookahead1();
@@ -65,6 +246,8 @@ impl Parse for Attrs {
                 path = Some(input.parse()?);
             } else if lh.peek(kw::rejection) {
                 parse_parenthesized_attribute(input, &mut rejection)?;
+            } else if lh.peek(kw::view) {
+                parse_parenthesized_attribute(input, &mut view)?;
             } else {
                 return Err(lh.error());
             }
@@ -72,13 +255,21 @@ impl Parse for Attrs {
             let _ = input.parse::<Token![,]>();
         }
 
-        Ok(Self { path, rejection })
+        Ok(Self {
+            path,
+            rejection,
+            view,
+        })
     }
 }
 
 impl Combine for Attrs {
     fn combine(mut self, other: Self) -> syn::Result<Self> {
-        let Self { path, rejection } = other;
+        let Self {
+            path,
+            rejection,
+            view,
+        } = other;
         if let Some(path) = path {
             if self.path.is_some() {
                 return Err(syn::Error::new_spanned(
@@ -89,48 +280,157 @@ impl Combine for Attrs {
             self.path = Some(path);
         }
         combine_attribute(&mut self.rejection, rejection)?;
+        combine_attribute(&mut self.view, view)?;
         Ok(self)
     }
 }
 
+/// The `#[typed_path]` attribute of a named field.
+#[derive(Default)]
+struct FieldAttrs {
+    skip: Option<kw::skip>,
+}
+
+impl Parse for FieldAttrs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let skip = input.parse()?;
+        let _ = input.parse::<Token![,]>();
+        Ok(Self { skip })
+    }
+}
+
+impl Combine for FieldAttrs {
+    fn combine(self, other: Self) -> syn::Result<Self> {
+        match (self.skip, other.skip) {
+            (Some(_), Some(skip)) => Err(syn::Error::new_spanned(
+                skip,
+                "`skip` specified more than once",
+            )),
+            (skip, other) => Ok(Self {
+                skip: skip.or(other),
+            }),
+        }
+    }
+}
+
+/// The named fields without a capture in `path`, set to their `Default` when parsing.
+///
+/// Only `PhantomData` fields and fields marked `#[typed_path(skip)]` can be left out of the path,
+/// so that a misspelled capture is an error rather than a field that is always defaulted.
+fn defaulted_fields<'a>(
+    fields: &'a syn::FieldsNamed,
+    captures: &[Ident],
+    path: &LitStr,
+) -> syn::Result<Vec<&'a Ident>> {
+    let mut defaulted = Vec::new();
+    for field in &fields.named {
+        let Some(ident) = &field.ident else {
+            continue;
+        };
+        let FieldAttrs { skip } = crate::attr_parsing::parse_attrs("typed_path", &field.attrs)?;
+
+        match skip {
+            Some(skip) if captures.contains(ident) => {
+                return Err(syn::Error::new_spanned(
+                    skip,
+                    format!("`{ident}` is captured by the path and cannot be skipped"),
+                ))
+            }
+            _ if captures.contains(ident) => {}
+            Some(_) => defaulted.push(ident),
+            None if is_phantom_data(&field.ty) => defaulted.push(ident),
+            None => {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    format!(
+                        "`{ident}` has no capture in {:?}. Add `:{ident}` to the path, or mark \
+                         the field `#[typed_path(skip)]` to set it to its `Default`",
+                        path.value(),
+                    ),
+                ))
+            }
+        }
+    }
+    Ok(defaulted)
+}
+
+fn is_phantom_data(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(ty) => ty
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
 fn expand_named_fields(
+    fields: &syn::FieldsNamed,
     ident: &syn::Ident,
+    generics: &StructGenerics,
     path: LitStr,
     segments: &[Segment],
-    _rejection: Option<syn::Path>,
-) -> TokenStream {
+    rejection: Option<syn::Path>,
+) -> syn::Result<TokenStream> {
     let format_str = format_str_from_path(segments);
+    let format_args = format_args_from_path(&path, segments);
     let captures = captures_from_path(segments);
+    let defaulted = defaulted_fields(fields, &captures, &path)?;
+    let rejection = rejection_type(rejection);
+    let route_items = route_items(&path, segments);
+    let segments_const = segments_const(ident, &generics.typed_path, &path, segments);
+    let from_path = from_path_fn(
+        &path,
+        segments,
+        quote! {
+            Self {
+                #(#captures,)*
+                #(#defaulted: ::std::default::Default::default(),)*
+            }
+        },
+    );
 
+    let (impl_generics, ty_generics, where_clause) = generics.typed_path.split_for_impl();
     let typed_path_impl = quote_spanned! {path.span()=>
         #[automatically_derived]
-        impl crate::routes::typed_path::TypedPath for #ident {
-            const PATH: &'static str = #path;
+        impl #impl_generics crate::routes::typed_path::TypedPath for #ident #ty_generics
+        #where_clause
+        {
+            #route_items
+        }
+
+        #[automatically_derived]
+        impl #impl_generics crate::routes::typed_path::FromPath for #ident #ty_generics
+        #where_clause
+        {
+            type Rejection = #rejection;
+
+            #from_path
         }
+
+        #segments_const
     };
 
+    let (impl_generics, ty_generics, where_clause) = generics.display.split_for_impl();
     let display_impl = quote_spanned! {path.span()=>
         #[automatically_derived]
-        impl ::std::fmt::Display for #ident {
-            #[allow(clippy::unnecessary_to_owned)]
+        impl #impl_generics ::std::fmt::Display for #ident #ty_generics #where_clause {
             fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                let Self { #(#captures,)* } = self;
+                let Self { #(#captures,)* .. } = self;
                 write!(
                     f,
                     #format_str,
-                    #(
-                        #captures = crate::routes::helpers::utf8_percent_encode(
-                            &#captures.to_string(),
-                            crate::routes::helpers::PATH_SEGMENT,
-                        )
-                    ),*
+                    #(#format_args),*
                 )
             }
         }
     };
 
     let to_href_impl = quote_spanned! {path.span()=>
-        impl ::leptos_router::components::ToHref for #ident {
+        impl #impl_generics ::leptos_router::components::ToHref for #ident #ty_generics
+        #where_clause
+        {
             fn to_href(&self) -> Box<dyn Fn() -> String> {
                 let s = self.to_string();
                 Box::new(move || s.clone())
@@ -138,47 +438,28 @@ fn expand_named_fields(
         }
     };
 
-    quote! {
+    Ok(quote! {
         #typed_path_impl
         #display_impl
         #to_href_impl
-    }
+    })
 }
 
 fn expand_unnamed_fields(
     fields: &syn::FieldsUnnamed,
     ident: &syn::Ident,
+    generics: &StructGenerics,
     path: LitStr,
     segments: &[Segment],
-    _rejection: Option<syn::Path>,
+    rejection: Option<syn::Path>,
 ) -> syn::Result<TokenStream> {
-    let num_captures = segments
-        .iter()
-        .filter(|segment| match segment {
-            Segment::Capture(_, _) => true,
-            Segment::Static(_) => false,
-        })
-        .count();
-    let num_fields = fields.unnamed.len();
-    if num_fields != num_captures {
-        return Err(syn::Error::new_spanned(
-            fields,
-            format!(
-                "Mismatch in number of captures and fields. Path has {} but struct has {}",
-                simple_pluralize(num_captures, "capture"),
-                simple_pluralize(num_fields, "field"),
-            ),
-        ));
-    }
+    check_unnamed_fields(fields, segments, "struct")?;
 
     let destructure_self = segments
         .iter()
-        .filter_map(|segment| match segment {
-            Segment::Capture(capture, _) => Some(capture),
-            Segment::Static(_) => None,
-        })
+        .filter_map(|segment| segment.capture())
         .enumerate()
-        .map(|(idx, capture)| {
+        .map(|(idx, (capture, _))| {
             let idx = syn::Index {
                 index: idx as _,
                 span: Span::call_site(),
@@ -190,37 +471,53 @@ fn expand_unnamed_fields(
         });
 
     let format_str = format_str_from_path(segments);
+    let format_args = format_args_from_path(&path, segments);
     let captures = captures_from_path(segments);
+    let rejection = rejection_type(rejection);
+    let route_items = route_items(&path, segments);
+    let segments_const = segments_const(ident, &generics.typed_path, &path, segments);
+    let from_path = from_path_fn(&path, segments, quote! { Self(#(#captures,)*) });
 
+    let (impl_generics, ty_generics, where_clause) = generics.typed_path.split_for_impl();
     let typed_path_impl = quote_spanned! {path.span()=>
         #[automatically_derived]
-        impl crate::routes::typed_path::TypedPath for #ident {
-            const PATH: &'static str = #path;
+        impl #impl_generics crate::routes::typed_path::TypedPath for #ident #ty_generics
+        #where_clause
+        {
+            #route_items
+        }
+
+        #[automatically_derived]
+        impl #impl_generics crate::routes::typed_path::FromPath for #ident #ty_generics
+        #where_clause
+        {
+            type Rejection = #rejection;
+
+            #from_path
         }
+
+        #segments_const
     };
 
+    let (impl_generics, ty_generics, where_clause) = generics.display.split_for_impl();
     let display_impl = quote_spanned! {path.span()=>
         #[automatically_derived]
-        impl ::std::fmt::Display for #ident {
-            #[allow(clippy::unnecessary_to_owned)]
+        impl #impl_generics ::std::fmt::Display for #ident #ty_generics #where_clause {
             fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
                 let Self { #(#destructure_self)* } = self;
                 write!(
                     f,
                     #format_str,
-                    #(
-                        #captures = crate::routes::helpers::utf8_percent_encode(
-                            &#captures.to_string(),
-                            crate::routes::helpers::PATH_SEGMENT,
-                        )
-                    ),*
+                    #(#format_args),*
                 )
             }
         }
     };
 
     let to_href_impl = quote_spanned! {path.span()=>
-        impl ::leptos_router::components::ToHref for #ident {
+        impl #impl_generics ::leptos_router::components::ToHref for #ident #ty_generics
+        #where_clause
+        {
             fn to_href(&self) -> Box<dyn Fn() -> String> {
                 let s = self.to_string();
                 Box::new(move || s.clone())
@@ -235,6 +532,29 @@ fn expand_unnamed_fields(
     })
 }
 
+fn check_unnamed_fields(
+    fields: &syn::FieldsUnnamed,
+    segments: &[Segment],
+    kind: &str,
+) -> syn::Result<()> {
+    let num_captures = segments
+        .iter()
+        .filter(|segment| segment.capture().is_some())
+        .count();
+    let num_fields = fields.unnamed.len();
+    if num_fields != num_captures {
+        return Err(syn::Error::new_spanned(
+            fields,
+            format!(
+                "Mismatch in number of captures and fields. Path has {} but {kind} has {}",
+                simple_pluralize(num_captures, "capture"),
+                simple_pluralize(num_fields, "field"),
+            ),
+        ));
+    }
+    Ok(())
+}
+
 fn simple_pluralize(count: usize, word: &str) -> String {
     if count == 1 {
         format!("{count} {word}")
@@ -245,31 +565,43 @@ fn simple_pluralize(count: usize, word: &str) -> String {
 
 fn expand_unit_fields(
     ident: &syn::Ident,
+    generics: &StructGenerics,
     path: LitStr,
-    _rejection: Option<syn::Path>,
+    rejection: Option<syn::Path>,
 ) -> syn::Result<TokenStream> {
-    for segment in parse_path(&path)? {
-        match segment {
-            Segment::Capture(_, span) => {
-                return Err(syn::Error::new(
-                    span,
-                    "Typed paths for unit structs cannot contain captures",
-                ));
-            }
-            Segment::Static(_) => {}
-        }
-    }
+    let segments = parse_path(&path)?;
+    check_no_captures(&segments, "unit structs")?;
+
+    let rejection = rejection_type(rejection);
+    let route_items = route_items(&path, &segments);
+    let segments_const = segments_const(ident, &generics.typed_path, &path, &segments);
+    let from_path = from_path_fn(&path, &segments, quote! { Self });
 
+    let (impl_generics, ty_generics, where_clause) = generics.typed_path.split_for_impl();
     let typed_path_impl = quote_spanned! {path.span()=>
         #[automatically_derived]
-        impl crate::routes::typed_path::TypedPath for #ident {
-            const PATH: &'static str = #path;
+        impl #impl_generics crate::routes::typed_path::TypedPath for #ident #ty_generics
+        #where_clause
+        {
+            #route_items
+        }
+
+        #[automatically_derived]
+        impl #impl_generics crate::routes::typed_path::FromPath for #ident #ty_generics
+        #where_clause
+        {
+            type Rejection = #rejection;
+
+            #from_path
         }
+
+        #segments_const
     };
 
+    let (impl_generics, ty_generics, where_clause) = generics.display.split_for_impl();
     let display_impl = quote_spanned! {path.span()=>
         #[automatically_derived]
-        impl ::std::fmt::Display for #ident {
+        impl #impl_generics ::std::fmt::Display for #ident #ty_generics #where_clause {
             fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
                 write!(f, #path)
             }
@@ -277,7 +609,9 @@ fn expand_unit_fields(
     };
 
     let to_href_impl = quote_spanned! {path.span()=>
-        impl ::leptos_router::components::ToHref for #ident {
+        impl #impl_generics ::leptos_router::components::ToHref for #ident #ty_generics
+        #where_clause
+        {
             fn to_href(&self) -> Box<dyn Fn() -> String> {
                 let s = self.to_string();
                 Box::new(move || s.clone())
@@ -292,24 +626,498 @@ fn expand_unit_fields(
     })
 }
 
+/// Expands an enum with a `#[typed_path]` on each variant.
+///
+/// `TypedPath` has a path and a route for each variant, and parsing tries the variants in order
+/// and returns the first one matching the path. Type parameters get the bounds the fields of all
+/// variants need, as for structs. When any variant has a `view(...)`, all of them must, and an
+/// inherent `Routes` function returns the route of every variant with its own view, rendered as
+/// `<Enum::Routes />` inside `<Routes>`.
+fn expand_enum(item_enum: ItemEnum) -> syn::Result<TokenStream> {
+    let ItemEnum {
+        attrs,
+        ident,
+        generics,
+        variants,
+        ..
+    } = &item_enum;
+
+    let Attrs {
+        path,
+        rejection,
+        view,
+    } = crate::attr_parsing::parse_attrs("typed_path", attrs)?;
+    if let Some(path) = path {
+        return Err(syn::Error::new_spanned(
+            path,
+            "enums take a path on each variant: `#[typed_path(\"/foo/bar\")]`",
+        ));
+    }
+    if let Some((kw, _)) = view {
+        return Err(syn::Error::new_spanned(
+            kw,
+            "`view` is only supported on enum variants",
+        ));
+    }
+
+    if variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "`#[derive(TypedPath)]` needs at least one variant",
+        ));
+    }
+
+    let rejection = rejection_type(rejection.map(second));
+    let from_request_parts = from_request_parts_impl(ident, generics);
+
+    let mut paths = Vec::new();
+    let mut segments_types = Vec::new();
+    let mut segments_values = Vec::new();
+    let mut display_arms = Vec::new();
+    let mut parsers = Vec::new();
+    let mut parser_idents = Vec::new();
+    let mut routes = Vec::new();
+    let mut missing_view = None;
+    let mut variant_segments = Vec::new();
+
+    for (index, variant) in variants.iter().enumerate() {
+        let variant_ident = &variant.ident;
+        let Attrs {
+            path,
+            rejection,
+            view,
+        } = crate::attr_parsing::parse_attrs("typed_path", &variant.attrs)?;
+
+        if let Some((kw, _)) = rejection {
+            return Err(syn::Error::new_spanned(
+                kw,
+                "`rejection` applies to every variant, set it on the enum",
+            ));
+        }
+
+        let path = path.ok_or_else(|| {
+            syn::Error::new_spanned(variant_ident, "Missing path: `#[typed_path(\"/foo/bar\")]`")
+        })?;
+        let segments = parse_path(&path)?;
+        let captures = captures_from_path(&segments);
+
+        let (pattern, construct) = match &variant.fields {
+            syn::Fields::Named(fields) => {
+                let defaulted = defaulted_fields(fields, &captures, &path)?;
+                (
+                    quote! { Self::#variant_ident { #(#captures,)* .. } },
+                    quote! {
+                        #ident::#variant_ident {
+                            #(#captures,)*
+                            #(#defaulted: ::std::default::Default::default(),)*
+                        }
+                    },
+                )
+            }
+            syn::Fields::Unnamed(fields) => {
+                check_unnamed_fields(fields, &segments, "variant")?;
+                let indices = (0..captures.len()).map(syn::Index::from);
+                (
+                    quote! { Self::#variant_ident { #(#indices: #captures,)* } },
+                    quote! { #ident::#variant_ident(#(#captures,)*) },
+                )
+            }
+            syn::Fields::Unit => {
+                check_no_captures(&segments, "unit variants")?;
+                (
+                    quote! { Self::#variant_ident },
+                    quote! { #ident::#variant_ident },
+                )
+            }
+        };
+
+        let format_str = format_str_from_path(&segments);
+        let format_args = format_args_from_path(&path, &segments);
+        display_arms.push(quote_spanned! {path.span()=>
+            #pattern => write!(
+                f,
+                #format_str,
+                #(#format_args),*
+            ),
+        });
+
+        let parser_ident = Ident::new(&format!("from_path_{index}"), Span::mixed_site());
+        let body = from_path_body(&path, &segments, construct);
+        parsers.push(quote_spanned! {path.span()=>
+            let #parser_ident = |
+                path: &str,
+            | -> ::std::result::Result<Self, crate::routes::typed_path::PathRejection> {
+                #body
+            };
+        });
+        parser_idents.push(parser_ident);
+
+        let (segments_type, segments_value) = router_segments_type_and_value(&path, &segments);
+        match view {
+            Some((_, view)) => {
+                routes.push(quote_spanned! {path.span()=>
+                    ::leptos_router::NestedRoute::new(#segments_value, #view)
+                });
+            }
+            None => missing_view = missing_view.or(Some(variant_ident)),
+        }
+        paths.push(path);
+        segments_types.push(segments_type);
+        segments_values.push(segments_value);
+        variant_segments.push(segments);
+    }
+
+    let generics = StructGenerics::new(
+        generics,
+        variants
+            .iter()
+            .zip(&variant_segments)
+            .flat_map(|(variant, segments)| field_segments(&variant.fields, segments)),
+    );
+
+    let first_path = &paths[0];
+    let routes_impl = match missing_view {
+        _ if routes.is_empty() => None,
+        Some(variant_ident) => {
+            return Err(syn::Error::new_spanned(
+                variant_ident,
+                "Missing view: other variants have one, add `view(Component)` to this one too",
+            ))
+        }
+        None => {
+            let doc = format!(
+                "The route of every variant, rendered as `<{ident}::Routes />` inside `<Routes>`."
+            );
+            let (impl_generics, ty_generics, where_clause) = item_enum.generics.split_for_impl();
+            Some(quote! {
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    #[doc = #doc]
+                    #[allow(non_snake_case)]
+                    pub fn Routes() -> impl ::leptos_router::MatchNestedRoutes
+                           + ::std::clone::Clone
+                           + ::std::marker::Send
+                           + 'static {
+                        (#(#routes,)*)
+                    }
+                }
+            })
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.typed_path.split_for_impl();
+    let typed_path_impl = quote! {
+        #[automatically_derived]
+        impl #impl_generics crate::routes::typed_path::TypedPath for #ident #ty_generics
+        #where_clause
+        {
+            const PATH: &'static str = #first_path;
+
+            const PATHS: &'static [&'static str] = &[#(#paths),*];
+
+            type Routes<View>
+                = (#(::leptos_router::NestedRoute<#segments_types, (), (), View>,)*)
+            where
+                View: ::leptos_router::ChooseView;
+
+            fn routes<View>(view: View, ssr: ::leptos_router::SsrMode) -> Self::Routes<View>
+            where
+                View: ::leptos_router::ChooseView,
+            {
+                (#(
+                    ::leptos_router::NestedRoute::new(
+                        #segments_values,
+                        ::std::clone::Clone::clone(&view),
+                    )
+                    .ssr_mode(::std::clone::Clone::clone(&ssr)),
+                )*)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics crate::routes::typed_path::FromPath for #ident #ty_generics
+        #where_clause
+        {
+            type Rejection = #rejection;
+
+            fn from_path(path: &str) -> ::std::result::Result<Self, Self::Rejection> {
+                #(#parsers)*
+                crate::routes::helpers::with_rejection(|| {
+                    #(
+                        match #parser_idents(path) {
+                            ::std::result::Result::Err(
+                                crate::routes::typed_path::PathRejection::NoMatch,
+                            ) => {}
+                            result => return result,
+                        }
+                    )*
+                    ::std::result::Result::Err(crate::routes::typed_path::PathRejection::NoMatch)
+                })
+            }
+        }
+
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.display.split_for_impl();
+    Ok(quote! {
+        #typed_path_impl
+
+        #routes_impl
+
+        #[automatically_derived]
+        impl #impl_generics ::std::fmt::Display for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self {
+                    #(#display_arms)*
+                }
+            }
+        }
+
+        impl #impl_generics ::leptos_router::components::ToHref for #ident #ty_generics
+        #where_clause
+        {
+            fn to_href(&self) -> Box<dyn Fn() -> String> {
+                let s = self.to_string();
+                Box::new(move || s.clone())
+            }
+        }
+
+        #from_request_parts
+    })
+}
+
+fn check_no_captures(segments: &[Segment], kind: &str) -> syn::Result<()> {
+    if let Some((_, span)) = segments.iter().find_map(Segment::capture) {
+        return Err(syn::Error::new(
+            span,
+            format!("Typed paths for {kind} cannot contain captures"),
+        ));
+    }
+    Ok(())
+}
+
+/// Generates `TypedPath::PATH` and `TypedPath::routes`, with a single route matching `path`.
+fn route_items(path: &LitStr, segments: &[Segment]) -> TokenStream {
+    let (ty, _) = router_segments_type_and_value(path, segments);
+
+    quote_spanned! {path.span()=>
+        const PATH: &'static str = #path;
+
+        type Routes<View>
+            = ::leptos_router::NestedRoute<#ty, (), (), View>
+        where
+            View: ::leptos_router::ChooseView;
+
+        fn routes<View>(view: View, ssr: ::leptos_router::SsrMode) -> Self::Routes<View>
+        where
+            View: ::leptos_router::ChooseView,
+        {
+            ::leptos_router::NestedRoute::new(Self::SEGMENTS, view).ssr_mode(ssr)
+        }
+    }
+}
+
+/// Generates an inherent `SEGMENTS` constant on a struct, the `leptos_router` segments `path!`
+/// would build for `path`.
+fn segments_const(
+    ident: &Ident,
+    generics: &syn::Generics,
+    path: &LitStr,
+    segments: &[Segment],
+) -> TokenStream {
+    let (ty, value) = router_segments_type_and_value(path, segments);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote_spanned! {path.span()=>
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// The segments [`path!`](::leptos_router::path) would build for the path.
+            pub const SEGMENTS: #ty = #value;
+        }
+    }
+}
+
+fn router_segments_type_and_value(
+    path: &LitStr,
+    segments: &[Segment],
+) -> (TokenStream, TokenStream) {
+    let mut types = Vec::new();
+    let mut values = Vec::new();
+
+    for segment in segments {
+        match segment {
+            Segment::Static(segment) if segment.is_empty() => {}
+            Segment::Static(segment) => {
+                types.push(quote! { ::leptos_router::StaticSegment<&'static str> });
+                values.push(quote! { ::leptos_router::StaticSegment(#segment) });
+            }
+            Segment::Capture(capture, _) => {
+                types.push(quote! { ::leptos_router::ParamSegment });
+                values.push(quote! { ::leptos_router::ParamSegment(#capture) });
+            }
+            Segment::Optional(capture, _) => {
+                types.push(quote! { ::leptos_router::OptionalParamSegment });
+                values.push(quote! { ::leptos_router::OptionalParamSegment(#capture) });
+            }
+            Segment::Wildcard(capture, _) => {
+                types.push(quote! { ::leptos_router::WildcardSegment });
+                values.push(quote! { ::leptos_router::WildcardSegment(#capture) });
+            }
+        }
+    }
+
+    let value = path.value();
+    if value.len() > 1 && value.ends_with('/') {
+        types.push(quote! { ::leptos_router::StaticSegment<&'static str> });
+        values.push(quote! { ::leptos_router::StaticSegment("/") });
+    }
+
+    (quote! { (#(#types,)*) }, quote! { (#(#values,)*) })
+}
+
+/// Generates `FromPath::from_path`, which matches `segments` one by one, binds each capture to
+/// a variable of the same name and returns `construct`.
+fn from_path_fn(path: &LitStr, segments: &[Segment], construct: TokenStream) -> TokenStream {
+    let body = from_path_body(path, segments, construct);
+
+    quote_spanned! {path.span()=>
+        fn from_path(path: &str) -> ::std::result::Result<Self, Self::Rejection> {
+            crate::routes::helpers::with_rejection(|| { #body })
+        }
+    }
+}
+
+/// The rejection type of `FromPath::from_path`, defaulting to `PathRejection`.
+///
+/// `FromPath::Rejection` requires `From<PathRejection>`, and the type is spanned to the
+/// `rejection(...)` attribute so a missing impl is reported there.
+fn rejection_type(rejection: Option<syn::Path>) -> TokenStream {
+    match rejection {
+        Some(rejection) => quote_spanned! {rejection.span()=> #rejection },
+        None => quote! { crate::routes::typed_path::PathRejection },
+    }
+}
+
+/// The body of [`from_path_fn`], parsing a `path: &str` in scope.
+fn from_path_body(path: &LitStr, segments: &[Segment], construct: TokenStream) -> TokenStream {
+    let parts = Ident::new("parts", Span::mixed_site());
+    let part = Ident::new("part", Span::mixed_site());
+
+    let match_segments = segments.iter().map(|segment| match segment {
+        Segment::Static(segment) => quote_spanned! {path.span()=>
+            if #parts.next() != ::std::option::Option::Some(#segment) {
+                return ::std::result::Result::Err(crate::routes::typed_path::PathRejection::NoMatch);
+            }
+        },
+        Segment::Capture(capture, span) => {
+            let ident = format_ident!("{}", capture, span = *span);
+            quote_spanned! {path.span()=>
+                let #ident = match #parts.next() {
+                    ::std::option::Option::Some(#part) if !#part.is_empty() => {
+                        crate::routes::helpers::parse_capture(#capture, #part)?
+                    }
+                    _ => {
+                        return ::std::result::Result::Err(
+                            crate::routes::typed_path::PathRejection::NoMatch,
+                        )
+                    }
+                };
+            }
+        }
+        Segment::Optional(capture, span) => {
+            let ident = format_ident!("{}", capture, span = *span);
+            quote_spanned! {path.span()=>
+                let #ident = crate::routes::helpers::parse_optional(#capture, #parts.next())?;
+            }
+        }
+        Segment::Wildcard(capture, span) => {
+            let ident = format_ident!("{}", capture, span = *span);
+            quote_spanned! {path.span()=>
+                let #ident = crate::routes::helpers::parse_wildcard(#capture, &mut #parts)?;
+            }
+        }
+    });
+
+    quote_spanned! {path.span()=>
+        let mut #parts = crate::routes::helpers::strip_query(path).split('/');
+        #(#match_segments)*
+        if #parts.next().is_some() {
+            return ::std::result::Result::Err(crate::routes::typed_path::PathRejection::NoMatch);
+        }
+        ::std::result::Result::Ok(#construct)
+    }
+}
+
 fn format_str_from_path(segments: &[Segment]) -> String {
+    let mut format_str = String::new();
+    for (index, segment) in segments.iter().enumerate() {
+        // Optional segments write their own `/`, unless only the root precedes them.
+        let separator = match segment {
+            _ if index == 0 => "",
+            Segment::Optional(..) if !follows_root(segments, index) => "",
+            _ => "/",
+        };
+        format_str.push_str(separator);
+        match segment {
+            Segment::Static(segment) => format_str.push_str(segment),
+            Segment::Capture(capture, _)
+            | Segment::Optional(capture, _)
+            | Segment::Wildcard(capture, _) => format_str.push_str(&format!("{{{capture}}}")),
+        }
+    }
+    format_str
+}
+
+/// Whether the segment at `index` comes right after the root `/`.
+fn follows_root(segments: &[Segment], index: usize) -> bool {
+    index == 1 && matches!(&segments[0], Segment::Static(segment) if segment.is_empty())
+}
+
+/// The named arguments of the `write!` formatting [`format_str_from_path`], binding each capture
+/// to its percent-encoded value.
+///
+/// Optional segments are written with their leading `/`, or not at all when `None`. An empty
+/// optional value fails the formatting, as its trailing `/` would be parsed back as `None`.
+/// Wildcards encode each of their segments separately, keeping the `/` between them.
+fn format_args_from_path(path: &LitStr, segments: &[Segment]) -> Vec<TokenStream> {
     segments
         .iter()
-        .map(|segment| match segment {
-            Segment::Capture(capture, _) => format!("{{{capture}}}"),
-            Segment::Static(segment) => segment.to_owned(),
+        .enumerate()
+        .filter_map(|(index, segment)| match segment {
+            Segment::Capture(capture, span) => {
+                let ident = format_ident!("{}", capture, span = *span);
+                Some(quote_spanned! {path.span()=>
+                    #ident = crate::routes::helpers::utf8_percent_encode(
+                        &crate::routes::path_param::PathParam::to_segment(#ident),
+                        crate::routes::helpers::PATH_SEGMENT,
+                    )
+                })
+            }
+            Segment::Optional(capture, span) => {
+                let ident = format_ident!("{}", capture, span = *span);
+                let separator = if follows_root(segments, index) {
+                    ""
+                } else {
+                    "/"
+                };
+                Some(quote_spanned! {path.span()=>
+                    #ident = crate::routes::helpers::encode_optional(#ident, #separator)?
+                })
+            }
+            Segment::Wildcard(capture, span) => {
+                let ident = format_ident!("{}", capture, span = *span);
+                Some(quote_spanned! {path.span()=>
+                    #ident = crate::routes::helpers::encode_wildcard(#ident)
+                })
+            }
+            Segment::Static(_) => None,
         })
-        .collect::<Vec<_>>()
-        .join("/")
+        .collect()
 }
 
 fn captures_from_path(segments: &[Segment]) -> Vec<syn::Ident> {
     segments
         .iter()
-        .filter_map(|segment| match segment {
-            Segment::Capture(capture, span) => Some(format_ident!("{}", capture, span = *span)),
-            Segment::Static(_) => None,
-        })
+        .filter_map(Segment::capture)
+        .map(|(capture, span)| format_ident!("{}", capture, span = span))
         .collect::<Vec<_>>()
 }
 
@@ -324,22 +1132,121 @@ fn parse_path(path: &LitStr) -> syn::Result<Vec<Segment>> {
         return Err(syn::Error::new_spanned(path, "paths must start with a `/`"));
     }
 
-    path.value()
-        .split('/')
-        .map(|segment| {
-            if let Some(capture) = segment
-                .strip_prefix(':')
-                .or_else(|| segment.strip_prefix('*'))
-            {
+    let segments: Vec<_> = value.split('/').collect();
+    segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            if let Some(capture) = segment.strip_prefix(':') {
+                if let Some(capture) = capture.strip_suffix('?') {
+                    if index + 1 != segments.len() {
+                        return Err(syn::Error::new_spanned(
+                            path,
+                            format!(
+                                "optional segment `:{capture}?` must be the last segment of the \
+                                 path"
+                            ),
+                        ));
+                    }
+                    return Ok(Segment::Optional(capture.to_owned(), path.span()));
+                }
+            }
+
+            if let Some(capture) = segment.strip_prefix(':') {
                 Ok(Segment::Capture(capture.to_owned(), path.span()))
+            } else if let Some(capture) = segment.strip_prefix('*') {
+                if index + 1 != segments.len() {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        format!("wildcard `*{capture}` must be the last segment of the path"),
+                    ));
+                }
+                Ok(Segment::Wildcard(capture.to_owned(), path.span()))
             } else {
-                Ok(Segment::Static(segment.to_owned()))
+                Ok(Segment::Static(segment.to_string()))
             }
         })
         .collect()
 }
 
 enum Segment {
+    /// `:name`, a single segment.
     Capture(String, Span),
+    /// `:name?`, a last segment that may be missing.
+    Optional(String, Span),
+    /// `*name`, the rest of the path.
+    Wildcard(String, Span),
     Static(String),
 }
+
+impl Segment {
+    /// The name of the field bound by the segment, if it captures one.
+    fn capture(&self) -> Option<(&str, Span)> {
+        match self {
+            Self::Capture(capture, span)
+            | Self::Optional(capture, span)
+            | Self::Wildcard(capture, span) => Some((capture, *span)),
+            Self::Static(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(path: &str) -> String {
+        match parse_path(&LitStr::new(path, Span::call_site())) {
+            Ok(_) => panic!("`{path}` should be rejected"),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn optional_segments_must_end_the_path() {
+        assert!(parse_path(&LitStr::new("/reports/:year/:month?", Span::call_site())).is_ok());
+        assert_eq!(
+            parse_error("/reports/:year?/:month?"),
+            "optional segment `:year?` must be the last segment of the path"
+        );
+        assert_eq!(
+            parse_error("/:lang?/help"),
+            "optional segment `:lang?` must be the last segment of the path"
+        );
+    }
+
+    #[test]
+    fn only_skipped_and_phantom_fields_are_defaulted() {
+        let path = LitStr::new("/users/:id", Span::call_site());
+        let captures = [format_ident!("id")];
+
+        let fields: syn::FieldsNamed = parse_quote!({
+            id: u32,
+            marker: PhantomData<T>,
+            #[typed_path(skip)]
+            tab: Tab,
+        });
+        let defaulted = defaulted_fields(&fields, &captures, &path).unwrap();
+        assert_eq!(defaulted, ["marker", "tab"]);
+
+        let fields: syn::FieldsNamed = parse_quote!({ idd: u32 });
+        assert_eq!(
+            defaulted_fields(&fields, &captures, &path)
+                .unwrap_err()
+                .to_string(),
+            "`idd` has no capture in \"/users/:id\". Add `:idd` to the path, or mark the field \
+             `#[typed_path(skip)]` to set it to its `Default`"
+        );
+
+        let fields: syn::FieldsNamed = parse_quote!({
+            #[typed_path(skip)]
+            id: u32,
+        });
+        assert_eq!(
+            defaulted_fields(&fields, &captures, &path)
+                .unwrap_err()
+                .to_string(),
+            "`id` is captured by the path and cannot be skipped"
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt;
+
+use crate::routes::typed_path::PathRejection;
+
+/// The errors rendered by the app's error views.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppError {
+    PageNotFound,
+    Unauthorized,
+    /// The current path doesn't parse as the [`TypedPath`](crate::routes::typed_path::TypedPath)
+    /// of the route reading it.
+    InvalidRoute(PathRejection),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PageNotFound => f.write_str("page not found"),
+            Self::Unauthorized => f.write_str("unauthorized"),
+            Self::InvalidRoute(rejection) => write!(f, "invalid route: {rejection}"),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<PathRejection> for AppError {
+    fn from(rejection: PathRejection) -> Self {
+        Self::InvalidRoute(rejection)
+    }
+}
@@ -1,9 +1,15 @@
-use leptos::prelude::*;
+use leptos::{either::Either, prelude::*};
 use leptos_meta::*;
-use leptos_router::{components::*, path, MatchNestedRoutes};
 
+use leptos_router::{components::*, MatchNestedRoutes};
+
+pub mod errors;
 pub mod routes;
 
+pub use errors::AppError;
+
+use routes::{typed_path::TypedRoute, Help, Home, SomeParameterPath};
+
 pub fn shell(options: LeptosOptions) -> impl IntoView {
     view! {
         <!DOCTYPE html>
@@ -30,19 +36,14 @@ pub fn App() -> impl IntoView {
     // Provides context that manages stylesheets, titles, meta tags, etc.
     provide_meta_context();
 
-    // Routes are not typed...
-    // https://github.com/leptos-rs/leptos/issues/2175
-
     view! {
         <Stylesheet id="leptos" href="/pkg/prontuario-eletronico.css" />
 
         // sets the document title
         <Title text="Hermes" />
 
-        // There should be a way to make this Router somewhat typed
-        // and have a method of creating links directly to routes.
-        // See Dioxus impl using a enum and some prop macro voodoo
-        // For now, try to sync with our custom `TypedPath`
+        // Each route matches the `#[typed_path]` of its `TypedPath`, which is also
+        // what links are built from.
         <Router>
             <Navbar />
             <Routes fallback=|| {
@@ -51,9 +52,9 @@ pub fn App() -> impl IntoView {
                 view! { <ErrorTemplate outside_errors /> }
             }>
 
-            <Route path=path!("") view=HomePage />
-            <Route path=path!("/help") view=HelpPage />
-            <Route path=path!("/some/:parameter") view=SomeParameterPathPage />
+            <TypedRoute<Home> view=HomePage />
+            <TypedRoute<Help> view=HelpPage />
+            <TypedRoute<SomeParameterPath> view=SomeParameterPathPage />
 
             </Routes>
         </Router>
@@ -77,5 +78,17 @@ fn HelpPage() -> impl IntoView {
 
 #[component]
 fn SomeParameterPathPage() -> impl IntoView {
-    view! { <p>SomeParameterPath</p> }
+    let path = routes::typed_path::use_typed_path::<routes::SomeParameterPath>();
+
+    view! {
+        <p>SomeParameterPath</p>
+        {move || match path.get() {
+            Ok(path) => Either::Left(view! { <p>{path.parameter}</p> }),
+            Err(error) => {
+                let mut outside_errors = Errors::default();
+                outside_errors.insert_with_default_key(error);
+                Either::Right(view! { <ErrorTemplate outside_errors /> })
+            }
+        }}
+    }
 }
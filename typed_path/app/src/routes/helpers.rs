@@ -1,4 +1,11 @@
-use percent_encoding::{AsciiSet, CONTROLS};
+use std::{borrow::Cow, fmt};
+
+use percent_encoding::{percent_decode_str, AsciiSet, CONTROLS};
+
+use super::{
+    path_param::{OptionalParam, PathParam, WildcardParam},
+    typed_path::PathRejection,
+};
 
 pub use percent_encoding::utf8_percent_encode;
 
@@ -6,3 +13,114 @@ pub use percent_encoding::utf8_percent_encode;
 const FRAGMENT: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>').add(b'`');
 const PATH: &AsciiSet = &FRAGMENT.add(b'#').add(b'?').add(b'{').add(b'}');
 pub const PATH_SEGMENT: &AsciiSet = &PATH.add(b'/').add(b'%');
+
+/// Returns `path` without its query string and fragment.
+pub fn strip_query(path: &str) -> &str {
+    path.split(['?', '#']).next().unwrap_or_default()
+}
+
+/// Runs `parse`, converting its [`PathRejection`] into the rejection of the typed path.
+pub fn with_rejection<T, R>(parse: impl FnOnce() -> Result<T, PathRejection>) -> Result<T, R>
+where
+    R: From<PathRejection>,
+{
+    parse().map_err(R::from)
+}
+
+/// Percent-decodes `segment` and parses it into the type of the `capture` field.
+pub fn parse_capture<T>(capture: &'static str, segment: &str) -> Result<T, PathRejection>
+where
+    T: PathParam,
+{
+    let value = percent_decode_str(segment)
+        .decode_utf8()
+        .map_err(|_| PathRejection::InvalidEncoding { capture })?;
+
+    T::from_segment(&value).map_err(|err| PathRejection::InvalidCapture {
+        capture,
+        value: value.into_owned(),
+        error: err.to_string(),
+    })
+}
+
+/// Percent-encodes an optional capture after `separator`, or writes nothing when it is `None`.
+///
+/// An empty segment is an error, since it would be written as a trailing `/` that parses back as
+/// `None`.
+pub fn encode_optional<T>(value: &T, separator: &str) -> Result<String, fmt::Error>
+where
+    T: OptionalParam,
+{
+    match value.to_segment() {
+        Some(segment) if segment.is_empty() => Err(fmt::Error),
+        Some(segment) => Ok(format!(
+            "{separator}{}",
+            utf8_percent_encode(&segment, PATH_SEGMENT)
+        )),
+        None => Ok(String::new()),
+    }
+}
+
+/// Percent-decodes `segment`, if any, and parses it into the type of the `capture` field.
+///
+/// A missing or empty segment, such as the one after a trailing `/`, is parsed as `None`.
+pub fn parse_optional<T>(capture: &'static str, segment: Option<&str>) -> Result<T, PathRejection>
+where
+    T: OptionalParam,
+{
+    let value = match segment.filter(|segment| !segment.is_empty()) {
+        Some(segment) => Some(
+            percent_decode_str(segment)
+                .decode_utf8()
+                .map_err(|_| PathRejection::InvalidEncoding { capture })?,
+        ),
+        None => None,
+    };
+
+    T::from_segment(value.as_deref()).map_err(|err| PathRejection::InvalidCapture {
+        capture,
+        value: value.unwrap_or_default().into_owned(),
+        error: err.to_string(),
+    })
+}
+
+/// Percent-encodes each segment of a wildcard capture, joined with `/`.
+pub fn encode_wildcard<T>(value: &T) -> String
+where
+    T: WildcardParam,
+{
+    value
+        .to_segments()
+        .iter()
+        .map(|segment| utf8_percent_encode(segment, PATH_SEGMENT).to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Percent-decodes the rest of the path and parses it into the type of the `capture` wildcard.
+///
+/// Empty segments, such as the one after a trailing `/`, are skipped.
+pub fn parse_wildcard<'a, T>(
+    capture: &'static str,
+    segments: impl Iterator<Item = &'a str>,
+) -> Result<T, PathRejection>
+where
+    T: WildcardParam,
+{
+    let segments = segments
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            percent_decode_str(segment)
+                .decode_utf8()
+                .map(Cow::into_owned)
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| PathRejection::InvalidEncoding { capture })?;
+
+    let value = segments.join("/");
+    T::from_segments(segments).map_err(|err| PathRejection::InvalidCapture {
+        capture,
+        value,
+        error: err.to_string(),
+    })
+}
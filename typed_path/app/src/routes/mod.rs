@@ -1,4 +1,7 @@
 mod helpers;
+pub mod path_param;
+#[cfg(feature = "ssr")]
+pub mod server;
 pub mod typed_path;
 
 use app_macros::TypedPath;
@@ -0,0 +1,251 @@
+use std::{
+    borrow::Cow,
+    convert::Infallible,
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    path::{Component, PathBuf},
+};
+
+/// A value captured by a [`TypedPath`](super::typed_path::TypedPath) segment.
+///
+/// `#[derive(TypedPath)]` formats captures with [`PathParam::to_segment`] and parses them with
+/// [`PathParam::from_segment`], percent-encoding and decoding around them.
+///
+/// `#[derive(PathParam)]` implements it for newtypes, delegating to their field, and for enums
+/// with unit variants, which are written in kebab-case unless renamed:
+///
+/// ```rust,ignore
+/// #[derive(PathParam)]
+/// pub struct UserId(u32);
+///
+/// #[derive(PathParam)]
+/// pub enum Tab {
+///     Overview,
+///     #[path_param("faq")]
+///     FrequentlyAskedQuestions,
+/// }
+/// ```
+///
+/// Types whose `Display` and `FromStr` impls round-trip can use [`path_param_from_str!`]. `Uuid`,
+/// and chrono's `NaiveDate` and `NaiveTime`, implement it with the `uuid` and `chrono` features.
+pub trait PathParam: Sized {
+    /// The error returned when a segment can't be parsed.
+    type Err: fmt::Display;
+
+    /// Formats `self` as a path segment, before percent-encoding.
+    fn to_segment(&self) -> Cow<'_, str>;
+
+    /// Parses a percent-decoded path segment.
+    fn from_segment(segment: &str) -> Result<Self, Self::Err>;
+}
+
+/// Implements [`PathParam`] with the `Display` and `FromStr` impls of each type.
+///
+/// Crates depending on this one can use it for their own types. Foreign types can only get an
+/// impl here, next to the primitives below:
+///
+/// ```rust,ignore
+/// path_param_from_str!(uuid::Uuid, chrono::NaiveDate);
+/// ```
+#[macro_export]
+macro_rules! path_param_from_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl $crate::routes::path_param::PathParam for $ty {
+                type Err = <$ty as ::std::str::FromStr>::Err;
+
+                fn to_segment(&self) -> ::std::borrow::Cow<'_, str> {
+                    ::std::borrow::Cow::Owned(::std::string::ToString::to_string(self))
+                }
+
+                fn from_segment(segment: &str) -> ::std::result::Result<Self, Self::Err> {
+                    ::std::str::FromStr::from_str(segment)
+                }
+            }
+        )*
+    };
+}
+
+pub use path_param_from_str;
+
+path_param_from_str!(u8, u16, u32, u64, u128, usize);
+path_param_from_str!(i8, i16, i32, i64, i128, isize);
+path_param_from_str!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize
+);
+path_param_from_str!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize
+);
+path_param_from_str!(f32, f64, bool, char, IpAddr, Ipv4Addr, Ipv6Addr);
+
+#[cfg(feature = "uuid")]
+path_param_from_str!(uuid::Uuid);
+
+// The date and time types whose `Display` output is parsed back by their `FromStr`.
+#[cfg(feature = "chrono")]
+path_param_from_str!(chrono::NaiveDate, chrono::NaiveTime);
+
+impl PathParam for String {
+    type Err = Infallible;
+
+    fn to_segment(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+
+    fn from_segment(segment: &str) -> Result<Self, Self::Err> {
+        Ok(segment.to_owned())
+    }
+}
+
+/// A segment not matching any variant of an enum deriving [`PathParam`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownVariant {
+    pub expected: &'static [&'static str],
+}
+
+impl fmt::Display for UnknownVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected one of ")?;
+        for (i, variant) in self.expected.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{variant}`")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnknownVariant {}
+
+/// A value captured by an optional `:name?` segment of a
+/// [`TypedPath`](super::typed_path::TypedPath), implemented for `Option` of any [`PathParam`].
+///
+/// `None` is written by omitting the segment, and parsed from a path without it.
+pub trait OptionalParam: Sized {
+    /// The error returned when a segment can't be parsed.
+    type Err: fmt::Display;
+
+    /// Formats `self` as a path segment, before percent-encoding, or `None` to omit it.
+    fn to_segment(&self) -> Option<Cow<'_, str>>;
+
+    /// Parses a percent-decoded path segment, or `None` when the path doesn't have it.
+    fn from_segment(segment: Option<&str>) -> Result<Self, Self::Err>;
+}
+
+impl<T> OptionalParam for Option<T>
+where
+    T: PathParam,
+{
+    type Err = T::Err;
+
+    fn to_segment(&self) -> Option<Cow<'_, str>> {
+        self.as_ref().map(PathParam::to_segment)
+    }
+
+    fn from_segment(segment: Option<&str>) -> Result<Self, Self::Err> {
+        segment.map(T::from_segment).transpose()
+    }
+}
+
+/// The rest of a path, captured by a `*name` wildcard of a
+/// [`TypedPath`](super::typed_path::TypedPath).
+///
+/// Each segment is percent-encoded and decoded on its own, so the `/` between them are kept:
+/// `vec!["a", "b"]` is written as `a/b`, not `a%2Fb`.
+pub trait WildcardParam: Sized {
+    /// The error returned when the segments can't be parsed.
+    type Err: fmt::Display;
+
+    /// Formats `self` as path segments, before percent-encoding.
+    fn to_segments(&self) -> Vec<Cow<'_, str>>;
+
+    /// Parses the percent-decoded, non-empty segments of the rest of a path.
+    fn from_segments(segments: Vec<String>) -> Result<Self, Self::Err>;
+}
+
+impl<T> WildcardParam for Vec<T>
+where
+    T: PathParam,
+{
+    type Err = T::Err;
+
+    fn to_segments(&self) -> Vec<Cow<'_, str>> {
+        self.iter().map(PathParam::to_segment).collect()
+    }
+
+    fn from_segments(segments: Vec<String>) -> Result<Self, Self::Err> {
+        segments
+            .iter()
+            .map(|segment| T::from_segment(segment))
+            .collect()
+    }
+}
+
+impl WildcardParam for String {
+    type Err = Infallible;
+
+    fn to_segments(&self) -> Vec<Cow<'_, str>> {
+        self.split('/').map(Cow::Borrowed).collect()
+    }
+
+    fn from_segments(segments: Vec<String>) -> Result<Self, Self::Err> {
+        Ok(segments.join("/"))
+    }
+}
+
+/// Parsed as a relative path of plain file names. `..`, `.` and separators decoded from the URL
+/// are rejected, as they would let the path escape the directory it is joined to.
+impl WildcardParam for PathBuf {
+    type Err = InvalidPathSegment;
+
+    fn to_segments(&self) -> Vec<Cow<'_, str>> {
+        self.components()
+            .filter(|component| !matches!(component, Component::RootDir | Component::Prefix(_)))
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect()
+    }
+
+    fn from_segments(segments: Vec<String>) -> Result<Self, Self::Err> {
+        segments
+            .into_iter()
+            .map(|segment| {
+                let mut components = std::path::Path::new(&segment).components();
+                match (components.next(), components.next()) {
+                    (Some(Component::Normal(_)), None) if !segment.contains(['/', '\\']) => {
+                        Ok(segment)
+                    }
+                    _ => Err(InvalidPathSegment { segment }),
+                }
+            })
+            .collect()
+    }
+}
+
+/// A wildcard segment that isn't a plain file name, such as `..`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidPathSegment {
+    pub segment: String,
+}
+
+impl fmt::Display for InvalidPathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not a file name", self.segment)
+    }
+}
+
+impl std::error::Error for InvalidPathSegment {}
@@ -0,0 +1,316 @@
+//! Axum routes sharing the [`TypedPath`] structs of the Leptos UI, with the `ssr` feature.
+//!
+//! With `ssr`, `#[derive(TypedPath)]` also implements `FromRequestParts`, so a handler can take
+//! its path struct as its first extractor and be registered at that path:
+//!
+//! ```rust,ignore
+//! #[derive(TypedPath, Deserialize)]
+//! #[typed_path("/files/:id")]
+//! pub struct FileDownload {
+//!     pub id: u32,
+//! }
+//!
+//! async fn download(FileDownload { id }: FileDownload) -> impl IntoResponse { ... }
+//!
+//! let router = Router::new().typed_get(download);
+//! ```
+//!
+//! Typed paths are absolute: inside `Router::nest`, the path is parsed from the request's
+//! `OriginalUri`, prefix included.
+
+use axum::{
+    handler::Handler,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{delete, get, patch, post, put, MethodRouter},
+    Router,
+};
+
+use super::typed_path::{PathRejection, TypedPath};
+
+/// Registers handlers at the paths of the [`TypedPath`] they take as their first extractor.
+pub trait RouterExt<S>: sealed::Sealed {
+    /// Adds a `GET` route for `handler`.
+    fn typed_get<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, S>,
+        T: FirstExtractorIs<P> + 'static,
+        P: TypedPath;
+
+    /// Adds a `POST` route for `handler`.
+    fn typed_post<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, S>,
+        T: FirstExtractorIs<P> + 'static,
+        P: TypedPath;
+
+    /// Adds a `PUT` route for `handler`.
+    fn typed_put<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, S>,
+        T: FirstExtractorIs<P> + 'static,
+        P: TypedPath;
+
+    /// Adds a `PATCH` route for `handler`.
+    fn typed_patch<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, S>,
+        T: FirstExtractorIs<P> + 'static,
+        P: TypedPath;
+
+    /// Adds a `DELETE` route for `handler`.
+    fn typed_delete<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, S>,
+        T: FirstExtractorIs<P> + 'static,
+        P: TypedPath;
+}
+
+impl<S> RouterExt<S> for Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    fn typed_get<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, S>,
+        T: FirstExtractorIs<P> + 'static,
+        P: TypedPath,
+    {
+        typed_route::<P, S>(self, get(handler))
+    }
+
+    fn typed_post<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, S>,
+        T: FirstExtractorIs<P> + 'static,
+        P: TypedPath,
+    {
+        typed_route::<P, S>(self, post(handler))
+    }
+
+    fn typed_put<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, S>,
+        T: FirstExtractorIs<P> + 'static,
+        P: TypedPath,
+    {
+        typed_route::<P, S>(self, put(handler))
+    }
+
+    fn typed_patch<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, S>,
+        T: FirstExtractorIs<P> + 'static,
+        P: TypedPath,
+    {
+        typed_route::<P, S>(self, patch(handler))
+    }
+
+    fn typed_delete<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, S>,
+        T: FirstExtractorIs<P> + 'static,
+        P: TypedPath,
+    {
+        typed_route::<P, S>(self, delete(handler))
+    }
+}
+
+fn typed_route<P, S>(mut router: Router<S>, method_router: MethodRouter<S>) -> Router<S>
+where
+    P: TypedPath,
+    S: Clone + Send + Sync + 'static,
+{
+    let mut paths = Vec::new();
+    for path in P::PATHS.iter().flat_map(|path| axum_paths(path)) {
+        // Variants such as `/` and `/:lang?` can share a path, which Axum only takes once.
+        if !paths.contains(&path) {
+            router = router.route(&path, method_router.clone());
+            paths.push(path);
+        }
+    }
+    router
+}
+
+/// The paths to register `path` at in Axum.
+///
+/// Axum has no optional segments, so a path ending with `:year?` gives one path without the
+/// segment and one with it. Other captures already use Axum's `:id` and `*rest` syntax.
+fn axum_paths(path: &str) -> Vec<String> {
+    let mut paths = Vec::new();
+    let mut current = String::new();
+    for segment in path.split('/').skip(1) {
+        let segment = match segment.strip_prefix(':').and_then(|c| c.strip_suffix('?')) {
+            Some(capture) => {
+                paths.push(root_if_empty(&current));
+                format!(":{capture}")
+            }
+            None => segment.to_owned(),
+        };
+        current.push('/');
+        current.push_str(&segment);
+    }
+    paths.push(root_if_empty(&current));
+    paths
+}
+
+fn root_if_empty(path: &str) -> String {
+    if path.is_empty() {
+        "/".to_owned()
+    } else {
+        path.to_owned()
+    }
+}
+
+/// Implemented for the extractors of handlers whose first one is `P`, or a `Result` of it.
+///
+/// The first element of the tuple is Axum's marker for how the last extractor is built.
+pub trait FirstExtractorIs<P>: sealed::Sealed {}
+
+macro_rules! impl_first_extractor_is {
+    ($($ty:ident),*) => {
+        impl<M, P, $($ty,)*> FirstExtractorIs<P> for (M, P, $($ty,)*) where P: TypedPath {}
+        impl<M, P, $($ty,)*> sealed::Sealed for (M, P, $($ty,)*) where P: TypedPath {}
+
+        impl<M, P, E, $($ty,)*> FirstExtractorIs<P> for (M, Result<P, E>, $($ty,)*)
+        where
+            P: TypedPath,
+        {
+        }
+        impl<M, P, E, $($ty,)*> sealed::Sealed for (M, Result<P, E>, $($ty,)*) where P: TypedPath {}
+    };
+}
+
+impl_first_extractor_is!();
+impl_first_extractor_is!(T1);
+impl_first_extractor_is!(T1, T2);
+impl_first_extractor_is!(T1, T2, T3);
+impl_first_extractor_is!(T1, T2, T3, T4);
+impl_first_extractor_is!(T1, T2, T3, T4, T5);
+impl_first_extractor_is!(T1, T2, T3, T4, T5, T6);
+impl_first_extractor_is!(T1, T2, T3, T4, T5, T6, T7);
+impl_first_extractor_is!(T1, T2, T3, T4, T5, T6, T7, T8);
+impl_first_extractor_is!(T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_first_extractor_is!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+impl_first_extractor_is!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+impl_first_extractor_is!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+impl_first_extractor_is!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13);
+impl_first_extractor_is!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14);
+impl_first_extractor_is!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15);
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<S> Sealed for axum::Router<S> {}
+}
+
+/// Paths that don't match are not found, and invalid captures or queries are bad requests.
+impl IntoResponse for PathRejection {
+    fn into_response(self) -> Response {
+        let status = match self {
+            PathRejection::NoMatch => StatusCode::NOT_FOUND,
+            PathRejection::InvalidEncoding { .. }
+            | PathRejection::InvalidCapture { .. }
+            | PathRejection::InvalidQuery(_) => StatusCode::BAD_REQUEST,
+        };
+        (status, self.to_string()).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        extract::{FromRequestParts, OriginalUri},
+        http::Request,
+    };
+
+    use super::*;
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    #[typed_path("/files/:id")]
+    struct FileDownload {
+        id: u32,
+    }
+
+    async fn extract(uri: &str) -> Result<FileDownload, PathRejection> {
+        let (mut parts, ()) = Request::builder().uri(uri).body(()).unwrap().into_parts();
+        FileDownload::from_request_parts(&mut parts, &()).await
+    }
+
+    #[tokio::test]
+    async fn typed_paths_are_extracted_from_requests() {
+        assert_eq!(extract("/files/7").await, Ok(FileDownload { id: 7 }));
+        assert_eq!(
+            extract("/files/7?download=true").await,
+            Ok(FileDownload { id: 7 })
+        );
+        assert_eq!(extract("/users/7").await, Err(PathRejection::NoMatch));
+        assert!(matches!(
+            extract("/files/x").await,
+            Err(PathRejection::InvalidCapture { capture: "id", .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn nested_routers_extract_the_original_path() {
+        #[derive(app_macros::TypedPath, Debug, PartialEq)]
+        #[typed_path("/api/files/:id")]
+        struct ApiFile {
+            id: u32,
+        }
+
+        // As `Router::new().nest("/api", files)` passes `/api/files/7` to a handler of `files`.
+        let (mut parts, ()) = Request::builder()
+            .uri("/files/7")
+            .extension(OriginalUri("/api/files/7".parse().unwrap()))
+            .body(())
+            .unwrap()
+            .into_parts();
+        assert_eq!(
+            ApiFile::from_request_parts(&mut parts, &()).await,
+            Ok(ApiFile { id: 7 })
+        );
+    }
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    enum Download {
+        #[typed_path("/files/:id")]
+        File { id: u32 },
+        #[typed_path("/files/:id/:name")]
+        Named(u32, String),
+    }
+
+    #[tokio::test]
+    async fn enums_are_extracted_from_requests() {
+        async fn extract(uri: &str) -> Result<Download, PathRejection> {
+            let (mut parts, ()) = Request::builder().uri(uri).body(()).unwrap().into_parts();
+            Download::from_request_parts(&mut parts, &()).await
+        }
+
+        assert_eq!(extract("/files/7").await, Ok(Download::File { id: 7 }));
+        assert_eq!(
+            extract("/files/7/a%20b").await,
+            Ok(Download::Named(7, "a b".to_owned()))
+        );
+        assert_eq!(extract("/users/7").await, Err(PathRejection::NoMatch));
+    }
+
+    #[test]
+    fn axum_paths_keep_captures() {
+        assert_eq!(axum_paths("/"), ["/"]);
+        assert_eq!(axum_paths("/help"), ["/help"]);
+        assert_eq!(
+            axum_paths("/users/:id/files/*path"),
+            ["/users/:id/files/*path"]
+        );
+    }
+
+    #[test]
+    fn axum_paths_expand_optional_segments() {
+        assert_eq!(axum_paths("/:lang?"), ["/", "/:lang"]);
+        assert_eq!(
+            axum_paths("/reports/:year/:month?"),
+            ["/reports/:year", "/reports/:year/:month"]
+        );
+    }
+}
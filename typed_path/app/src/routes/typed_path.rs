@@ -1,7 +1,11 @@
-use std::{any::type_name, fmt};
+use std::{any::type_name, borrow::Cow, fmt, marker::PhantomData};
 
 use http::Uri;
-use serde::Serialize;
+use leptos::prelude::*;
+use leptos_router::{hooks::use_location, ChooseView, MatchNestedRoutes, SsrMode};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::AppError;
 
 /// A type safe path
 ///
@@ -9,8 +13,26 @@ use serde::Serialize;
 ///
 pub trait TypedPath: std::fmt::Display {
     /// The path with optional captures such as `/users/:id`.
+    ///
+    /// For an enum, the path of its first variant.
     const PATH: &'static str;
 
+    /// Every path `Self` can be formatted as: [`Self::PATH`], or the path of each variant of an
+    /// enum.
+    const PATHS: &'static [&'static str] = &[Self::PATH];
+
+    /// The routes matching [`Self::PATHS`], all rendering the same view.
+    type Routes<View>: MatchNestedRoutes + Clone + Send + 'static
+    where
+        View: ChooseView;
+
+    /// Builds a [`NestedRoute`](leptos_router::NestedRoute) for each of [`Self::PATHS`], with
+    /// the segments [`path!`](leptos_router::path) would build, such as
+    /// `(StaticSegment("users"), ParamSegment("id"))` for `/users/:id`.
+    fn routes<View>(view: View, ssr: SsrMode) -> Self::Routes<View>
+    where
+        View: ChooseView;
+
     fn raw_path() -> &'static str {
         Self::PATH
     }
@@ -29,6 +51,133 @@ pub trait TypedPath: std::fmt::Display {
     }
 }
 
+/// A [`TypedPath`] that can be parsed back from a path.
+///
+/// Derived along with [`TypedPath`], and implemented by [`WithQueryParams`] when its params can
+/// be deserialized.
+pub trait FromPath: TypedPath + Sized {
+    /// The error returned when a path can't be parsed as `Self`.
+    ///
+    /// [`PathRejection`] unless set with `#[typed_path("/users/:id", rejection(MyRejection))]`.
+    /// Axum handlers can only extract paths whose rejection implements `IntoResponse`.
+    type Rejection: From<PathRejection>;
+
+    /// Parses a path such as `/users/42` back into `Self`.
+    ///
+    /// Captures are percent-decoded and parsed with [`PathParam`](super::path_param::PathParam),
+    /// or [`OptionalParam`](super::path_param::OptionalParam) and
+    /// [`WildcardParam`](super::path_param::WildcardParam) for `:name?` and `*rest`. The query
+    /// string and fragment, if any, are ignored. Enums return the first variant matching the
+    /// path.
+    fn from_path(path: &str) -> Result<Self, Self::Rejection>;
+}
+
+/// A `<Route>` matching the path of `P`, so its pattern is only written in `#[typed_path]`.
+///
+/// An enum gets a route for each of its variants, all rendering `view`. Unlike the `Routes`
+/// function derived for enums whose variants all have a `view(...)`, this works with any typed
+/// path.
+///
+/// ```rust,ignore
+/// use crate::routes::SomeParameterPath;
+///
+/// <Routes fallback=|| "Not found.">
+///     <TypedRoute<SomeParameterPath> view=SomeParameterPathPage />
+/// </Routes>
+/// ```
+///
+/// `view!` only takes a single identifier as the path type, so it has to be imported.
+#[component(transparent)]
+pub fn TypedRoute<P>(
+    /// Set by `<TypedRoute<P> />`, which names the typed path this route matches.
+    #[prop(optional)]
+    path: PhantomData<P>,
+    /// The view for this route.
+    #[prop(into)]
+    view: ViewFn,
+    /// The mode that this route prefers during server-side rendering.
+    #[prop(optional)]
+    ssr: SsrMode,
+) -> P::Routes<impl ChooseView>
+where
+    P: TypedPath + Send + Sync + 'static,
+{
+    let PhantomData = path;
+    P::routes(move || view.run(), ssr)
+}
+
+/// Reads the current route, path and query string, as `T`.
+///
+/// When the current route doesn't parse as `T`, the memo holds its [`FromPath::Rejection`]
+/// converted into an [`AppError`], [`AppError::InvalidRoute`] for the default [`PathRejection`].
+/// The memo is a `SignalResult` source:
+///
+/// ```rust,ignore
+/// let path = use_typed_path::<SomeParameterPath>();
+/// signal_result_view_with_suspense!(|path| view! { <p>{path.parameter}</p> })
+/// ```
+pub fn use_typed_path<T>() -> Memo<Result<T, AppError>>
+where
+    T: FromPath + PartialEq + Send + Sync + 'static,
+    AppError: From<T::Rejection>,
+{
+    let location = use_location();
+    let (pathname, search) = (location.pathname, location.search);
+
+    Memo::new(move |_| {
+        pathname.with(|pathname| {
+            search.with(|search| {
+                T::from_path(&path_with_query(pathname, search)).map_err(AppError::from)
+            })
+        })
+    })
+}
+
+/// Joins a location's `pathname` and `search`, which doesn't start with `?`.
+fn path_with_query<'a>(pathname: &'a str, search: &str) -> Cow<'a, str> {
+    if search.is_empty() {
+        Cow::Borrowed(pathname)
+    } else {
+        Cow::Owned(format!("{pathname}?{search}"))
+    }
+}
+
+/// Why a path could not be parsed by [`FromPath::from_path`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathRejection {
+    /// The path doesn't match any of [`TypedPath::PATHS`].
+    NoMatch,
+    /// A capture is not valid percent-encoded UTF-8.
+    InvalidEncoding { capture: &'static str },
+    /// A capture could not be parsed into the type of its field.
+    InvalidCapture {
+        capture: &'static str,
+        value: String,
+        error: String,
+    },
+    /// The query string could not be deserialized.
+    InvalidQuery(String),
+}
+
+impl fmt::Display for PathRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoMatch => f.write_str("path doesn't match"),
+            Self::InvalidEncoding { capture } => {
+                write!(f, "`{capture}` is not valid percent-encoded UTF-8")
+            }
+            Self::InvalidCapture {
+                capture,
+                value,
+                error,
+            } => write!(f, "invalid `{capture}` {value:?}: {error}"),
+            Self::InvalidQuery(error) => write!(f, "invalid query string: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for PathRejection {}
+
 /// A [`TypedPath`] with query params.
 ///
 /// See [`TypedPath::with_query_params`] for more details.
@@ -70,4 +219,575 @@ where
     T: Serialize,
 {
     const PATH: &'static str = P::PATH;
+
+    const PATHS: &'static [&'static str] = P::PATHS;
+
+    type Routes<View>
+        = P::Routes<View>
+    where
+        View: ChooseView;
+
+    fn routes<View>(view: View, ssr: SsrMode) -> Self::Routes<View>
+    where
+        View: ChooseView,
+    {
+        P::routes(view, ssr)
+    }
+}
+
+impl<P, T> FromPath for WithQueryParams<P, T>
+where
+    P: FromPath,
+    T: Serialize + DeserializeOwned,
+{
+    type Rejection = P::Rejection;
+
+    fn from_path(path: &str) -> Result<Self, Self::Rejection> {
+        let (path_only, query) = path.split_once('?').unwrap_or((path, ""));
+        let query = query.split('#').next().unwrap_or_default();
+
+        Ok(WithQueryParams {
+            path: P::from_path(path_only)?,
+            params: serde_html_form::from_str(query)
+                .map_err(|err| PathRejection::InvalidQuery(err.to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::{
+        path_param::{PathParam, UnknownVariant},
+        Help, Home, SomeParameterPath,
+    };
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    enum Route {
+        #[typed_path("/", view(|| "home"))]
+        Home,
+        #[typed_path("/users/new", view(|| "new user"))]
+        NewUser,
+        #[typed_path("/users/:id", view(|| "user"))]
+        User { id: u32 },
+        #[typed_path("/users/:id/posts/:post", view(|| "post"))]
+        Post(u32, String),
+        #[typed_path("/search/:query", view(|| "search"))]
+        Search {
+            query: String,
+            marker: PhantomData<()>,
+        },
+    }
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    enum Asset<Id, T> {
+        #[typed_path("/assets")]
+        List,
+        #[typed_path("/assets/:id")]
+        Item { id: Id, tenant: PhantomData<T> },
+        #[typed_path("/assets/:id/versions/:version")]
+        Version(Id, u32),
+    }
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    #[typed_path("/details/:id")]
+    struct Detail<Id> {
+        id: Id,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Acme;
+
+    #[derive(app_macros::PathParam, Debug, PartialEq)]
+    struct UserId(u32);
+
+    #[derive(Debug, PartialEq)]
+    struct NotFound(PathRejection);
+
+    impl From<PathRejection> for NotFound {
+        fn from(rejection: PathRejection) -> Self {
+            Self(rejection)
+        }
+    }
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    #[typed_path("/users/:id", rejection(NotFound))]
+    struct User {
+        id: UserId,
+    }
+
+    #[derive(app_macros::PathParam, Debug, PartialEq)]
+    enum Tab {
+        Overview,
+        #[path_param("faq")]
+        FrequentlyAskedQuestions,
+        ReleaseNotes,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Version(u8, u8);
+
+    impl fmt::Display for Version {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}.{}", self.0, self.1)
+        }
+    }
+
+    impl std::str::FromStr for Version {
+        type Err = &'static str;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (major, minor) = s.split_once('.').ok_or("expected `major.minor`")?;
+            Ok(Self(
+                major.parse().map_err(|_| "invalid major version")?,
+                minor.parse().map_err(|_| "invalid minor version")?,
+            ))
+        }
+    }
+
+    crate::path_param_from_str!(Version);
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    #[typed_path("/releases/:version")]
+    struct Release {
+        version: Version,
+    }
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    #[typed_path("/users/:user/:tab")]
+    struct UserTab {
+        user: UserId,
+        tab: Tab,
+    }
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    #[typed_path("/items/:id")]
+    struct TenantItem<T> {
+        id: u32,
+        tenant: PhantomData<T>,
+    }
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    #[typed_path("/projects/:id")]
+    struct Project {
+        id: u32,
+        #[typed_path(skip)]
+        highlight: Option<String>,
+    }
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    #[typed_path("/docs/*path")]
+    struct Docs {
+        path: Vec<String>,
+    }
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    #[typed_path("/files/:user/*path")]
+    struct File(UserId, std::path::PathBuf);
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    #[typed_path("/reports/:year/:month?")]
+    struct Reports {
+        year: u16,
+        month: Option<u8>,
+    }
+
+    #[derive(app_macros::TypedPath, Debug, PartialEq)]
+    #[typed_path("/:lang?")]
+    struct Localized(Option<String>);
+
+    #[test]
+    fn from_path_round_trips() {
+        let path = SomeParameterPath::new("a b/c".to_string());
+        let parsed = SomeParameterPath::from_path(&path.to_string()).unwrap();
+        assert_eq!(parsed, path);
+
+        assert!(Home::from_path("/").is_ok());
+        assert!(Help::from_path("/help?tab=faq").is_ok());
+    }
+
+    #[test]
+    fn enum_from_path_picks_first_matching_variant() {
+        for route in [
+            Route::Home,
+            Route::NewUser,
+            Route::User { id: 7 },
+            Route::Post(7, "a b".to_string()),
+            Route::Search {
+                query: "a b".to_string(),
+                marker: PhantomData,
+            },
+        ] {
+            assert_eq!(Route::from_path(&route.to_string()), Ok(route));
+        }
+
+        assert!(matches!(
+            Route::from_path("/users/me"),
+            Err(PathRejection::InvalidCapture { capture: "id", .. })
+        ));
+        assert_eq!(Route::from_path("/help"), Err(PathRejection::NoMatch));
+    }
+
+    #[test]
+    fn enums_have_a_route_for_each_variant() {
+        assert_eq!(Route::PATH, "/");
+        assert_eq!(
+            Route::PATHS,
+            [
+                "/",
+                "/users/new",
+                "/users/:id",
+                "/users/:id/posts/:post",
+                "/search/:query"
+            ]
+        );
+
+        let routes = Route::routes(|| "route", SsrMode::default());
+        let paths: Vec<String> = routes
+            .generate_routes()
+            .into_iter()
+            .map(|route| {
+                route
+                    .segments
+                    .iter()
+                    .map(|segment| format!("/{}", segment.as_raw_str()))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "",
+                "/users/new",
+                "/users/id",
+                "/users/id/posts/post",
+                "/search/query"
+            ]
+        );
+
+        // Enums are typed paths wherever one is expected.
+        let _: fn() -> Memo<Result<Route, AppError>> = use_typed_path::<Route>;
+        let parsed = WithQueryParams::<Route, Vec<(String, String)>>::from_path("/users/7?a=b");
+        assert_eq!(parsed.map(|path| path.path), Ok(Route::User { id: 7 }));
+    }
+
+    #[test]
+    fn typed_route_matches_the_path_of_its_type() {
+        let routes = view! { <TypedRoute<SomeParameterPath> view=|| "page" /> }.into_inner();
+        let paths: Vec<String> = routes
+            .generate_routes()
+            .into_iter()
+            .map(|route| {
+                route
+                    .segments
+                    .iter()
+                    .map(|segment| format!("/{}", segment.as_raw_str()))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(paths, ["/some/parameter"]);
+        assert!(routes.match_nested("/some/test").0.is_some());
+        assert!(routes.match_nested("/help").0.is_none());
+    }
+
+    #[test]
+    fn generic_from_path_round_trips() {
+        let detail = Detail { id: 42_u64 };
+        assert_eq!(detail.to_string(), "/details/42");
+        assert_eq!(Detail::from_path("/details/42"), Ok(detail));
+        assert_eq!(
+            Detail::<String>::from_path("/details/a%20b"),
+            Ok(Detail {
+                id: "a b".to_string()
+            })
+        );
+
+        let item = TenantItem::<Acme> {
+            id: 7,
+            tenant: PhantomData,
+        };
+        assert_eq!(TenantItem::from_path(&item.to_string()), Ok(item));
+    }
+
+    #[test]
+    fn generic_enums_round_trip() {
+        let item = Asset::<String, Acme>::Item {
+            id: "a b".to_owned(),
+            tenant: PhantomData,
+        };
+        assert_eq!(item.to_string(), "/assets/a%20b");
+        assert_eq!(Asset::from_path(&item.to_string()), Ok(item));
+        assert_eq!(
+            Asset::<u64, Acme>::from_path("/assets/3/versions/2"),
+            Ok(Asset::Version(3, 2))
+        );
+        assert_eq!(Asset::<u64, Acme>::from_path("/assets"), Ok(Asset::List));
+        assert_eq!(Asset::<u64, Acme>::PATHS.len(), 3);
+    }
+
+    #[test]
+    fn skipped_fields_are_defaulted() {
+        let project = Project {
+            id: 4,
+            highlight: Some("readme".to_owned()),
+        };
+        assert_eq!(project.to_string(), "/projects/4");
+        assert_eq!(
+            Project::from_path("/projects/4"),
+            Ok(Project {
+                id: 4,
+                highlight: None
+            })
+        );
+    }
+
+    #[test]
+    fn path_params_round_trip() {
+        let path = UserTab {
+            user: UserId(3),
+            tab: Tab::ReleaseNotes,
+        };
+        assert_eq!(path.to_string(), "/users/3/release-notes");
+        assert_eq!(UserTab::from_path("/users/3/release-notes"), Ok(path));
+        assert_eq!(
+            UserTab::from_path("/users/3/faq").map(|path| path.tab),
+            Ok(Tab::FrequentlyAskedQuestions)
+        );
+
+        assert_eq!(
+            UserTab::from_path("/users/3/overviews"),
+            Err(PathRejection::InvalidCapture {
+                capture: "tab",
+                value: "overviews".to_string(),
+                error: "expected one of `overview`, `faq`, `release-notes`".to_string(),
+            })
+        );
+        assert_eq!(
+            Tab::from_segment("FAQ"),
+            Err(UnknownVariant {
+                expected: &["overview", "faq", "release-notes"]
+            })
+        );
+
+        let release = Release {
+            version: Version(1, 2),
+        };
+        assert_eq!(release.to_string(), "/releases/1.2");
+        assert_eq!(Release::from_path("/releases/1.2"), Ok(release));
+        assert_eq!(
+            Release::from_path("/releases/1"),
+            Err(PathRejection::InvalidCapture {
+                capture: "version",
+                value: "1".to_string(),
+                error: "expected `major.minor`".to_string(),
+            })
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuids_are_path_params() {
+        #[derive(app_macros::TypedPath, Debug, PartialEq)]
+        #[typed_path("/sessions/:id")]
+        struct Session {
+            id: uuid::Uuid,
+        }
+
+        let path = Session {
+            id: uuid::Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8),
+        };
+        assert_eq!(
+            path.to_string(),
+            "/sessions/67e55044-10b1-426f-9247-bb680e5fe0c8"
+        );
+        assert_eq!(Session::from_path(&path.to_string()), Ok(path));
+        assert!(matches!(
+            Session::from_path("/sessions/42"),
+            Err(PathRejection::InvalidCapture { capture: "id", .. })
+        ));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_dates_are_path_params() {
+        #[derive(app_macros::TypedPath, Debug, PartialEq)]
+        #[typed_path("/agenda/:date/:time")]
+        struct Appointment {
+            date: chrono::NaiveDate,
+            time: chrono::NaiveTime,
+        }
+
+        let path = Appointment {
+            date: chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+            time: chrono::NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+        };
+        assert!(path.to_string().starts_with("/agenda/2024-02-29/"));
+        assert_eq!(Appointment::from_path(&path.to_string()), Ok(path));
+        assert!(matches!(
+            Appointment::from_path("/agenda/2023-02-29/09:30:00"),
+            Err(PathRejection::InvalidCapture {
+                capture: "date",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn from_path_returns_the_rejection() {
+        assert_eq!(User::from_path("/users/1"), Ok(User { id: UserId(1) }));
+        assert_eq!(
+            User::from_path("/posts/1"),
+            Err(NotFound(PathRejection::NoMatch))
+        );
+        assert_eq!(
+            User::from_path("/users/1/more").err(),
+            User::from_path("/users/1/more?page=2").err()
+        );
+    }
+
+    #[test]
+    fn locations_parse_with_their_query() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Page {
+            page: u32,
+        }
+
+        assert_eq!(path_with_query("/some/test", ""), "/some/test");
+        let path = path_with_query("/some/test", "page=2");
+        let parsed = WithQueryParams::<SomeParameterPath, Page>::from_path(&path).unwrap();
+        assert_eq!(parsed.params, Page { page: 2 });
+
+        assert_eq!(
+            Help::from_path("/").map_err(AppError::from).err(),
+            Some(AppError::InvalidRoute(PathRejection::NoMatch))
+        );
+    }
+
+    #[test]
+    fn serialize_only_query_params_are_typed_paths() {
+        #[derive(serde::Serialize)]
+        struct Filter<'a> {
+            tag: &'a str,
+        }
+
+        fn paths<P: TypedPath>(path: &P) -> (&'static [&'static str], Uri) {
+            (P::PATHS, path.to_uri())
+        }
+
+        let (paths, uri) = paths(&Help.with_query_params(Filter { tag: "a b" }));
+        assert_eq!(paths, Help::PATHS);
+        assert_eq!(uri.path(), "/help");
+        assert!(uri.query().unwrap().contains("tag=a+b"));
+    }
+
+    #[test]
+    fn optional_segments_are_omitted_when_none() {
+        let cases = [("/reports/2024", None), ("/reports/2024/3", Some(3))];
+        for (path, month) in cases {
+            let reports = Reports { year: 2024, month };
+            assert_eq!(reports.to_string(), path);
+            assert_eq!(Reports::from_path(path), Ok(reports));
+        }
+        assert_eq!(
+            Reports::from_path("/reports/2024/"),
+            Ok(Reports {
+                year: 2024,
+                month: None
+            })
+        );
+        assert_eq!(
+            Reports::from_path("/reports").err(),
+            Some(PathRejection::NoMatch)
+        );
+        assert_eq!(
+            Reports::from_path("/reports/2024/3/1").err(),
+            Some(PathRejection::NoMatch)
+        );
+
+        assert_eq!(Localized(None).to_string(), "/");
+        assert_eq!(Localized::from_path("/"), Ok(Localized(None)));
+        assert_eq!(Localized(Some("fr".to_owned())).to_string(), "/fr");
+        assert_eq!(
+            Localized::from_path("/fr"),
+            Ok(Localized(Some("fr".to_owned())))
+        );
+    }
+
+    #[test]
+    fn empty_optional_segments_are_not_formatted() {
+        use std::fmt::Write;
+
+        // `/` would parse back as `Localized(None)`.
+        assert!(write!(String::new(), "{}", Localized(Some(String::new()))).is_err());
+        assert_eq!(Localized::from_path("/"), Ok(Localized(None)));
+    }
+
+    #[test]
+    fn wildcards_encode_each_segment() {
+        let docs = Docs {
+            path: vec!["guides".to_owned(), "a/b c".to_owned()],
+        };
+        assert_eq!(docs.to_string(), "/docs/guides/a%2Fb%20c");
+        assert_eq!(Docs::from_path("/docs/guides/a%2Fb%20c"), Ok(docs));
+        assert_eq!(Docs::from_path("/docs/"), Ok(Docs { path: Vec::new() }));
+
+        let file = File(UserId(7), ["notes", "today.md"].iter().collect());
+        assert_eq!(file.to_string(), "/files/7/notes/today.md");
+        assert_eq!(File::from_path("/files/7/notes/today.md"), Ok(file));
+        assert!(matches!(
+            File::from_path("/files/7/notes/.."),
+            Err(PathRejection::InvalidCapture {
+                capture: "path",
+                ..
+            })
+        ));
+        assert!(matches!(
+            File::from_path("/files/7/%2Fetc"),
+            Err(PathRejection::InvalidCapture {
+                capture: "path",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn structs_have_a_single_path() {
+        assert_eq!(SomeParameterPath::PATH, "/some/:parameter");
+        assert_eq!(SomeParameterPath::raw_path(), "/some/:parameter");
+        assert_eq!(SomeParameterPath::PATHS, ["/some/:parameter"]);
+    }
+
+    #[test]
+    fn segments_match_path_macro() {
+        assert_eq!(Home::SEGMENTS, leptos_router::path!("/"));
+        assert_eq!(Help::SEGMENTS, leptos_router::path!("/help"));
+        assert_eq!(
+            SomeParameterPath::SEGMENTS,
+            leptos_router::path!("/some/:parameter")
+        );
+        assert_eq!(Docs::SEGMENTS, leptos_router::path!("/docs/*path"));
+        assert_eq!(
+            Reports::SEGMENTS,
+            leptos_router::path!("/reports/:year/:month?")
+        );
+    }
+
+    #[test]
+    fn from_path_rejects_other_paths() {
+        assert_eq!(Help::from_path("/").err(), Some(PathRejection::NoMatch));
+        assert_eq!(
+            Help::from_path("/help/more").err(),
+            Some(PathRejection::NoMatch)
+        );
+        assert_eq!(
+            SomeParameterPath::from_path("/some/").err(),
+            Some(PathRejection::NoMatch)
+        );
+        assert_eq!(
+            SomeParameterPath::from_path("/some/%FF").err(),
+            Some(PathRejection::InvalidEncoding {
+                capture: "parameter"
+            })
+        );
+    }
 }